@@ -0,0 +1,43 @@
+use std::{fs::read_to_string, hint::black_box};
+
+use aoc_rs::registry;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// Benchmarks parse, part 1 and part 2 of every registered day with an input file,
+/// one group per year.
+fn benchmark_puzzles(c: &mut Criterion) {
+    for year in registry::years() {
+        let mut group = c.benchmark_group(year.to_string());
+
+        for puzzle in registry::year(year) {
+            let Ok(input) = read_to_string(puzzle.input_path()) else {
+                continue;
+            };
+            let parsed = puzzle
+                .parse(&input)
+                .unwrap_or_else(|err| panic!("invalid input: {err}"));
+            let day = format!("day{:02}", puzzle.day);
+
+            group.bench_function(format!("{day}/parse"), |b| {
+                b.iter(|| puzzle.parse(black_box(&input)))
+            });
+
+            let [part_1, part_2] = puzzle.implemented;
+            if part_1 {
+                group.bench_function(format!("{day}/part1"), |b| {
+                    b.iter(|| black_box(&parsed).part_1())
+                });
+            }
+            if part_2 {
+                group.bench_function(format!("{day}/part2"), |b| {
+                    b.iter(|| black_box(&parsed).part_2())
+                });
+            }
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, benchmark_puzzles);
+criterion_main!(benches);
@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_rs::registry;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = registry::get(2023, 5).unwrap().parse(input);
+    }
+});
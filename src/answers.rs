@@ -0,0 +1,101 @@
+use rustc_hash::FxHashMap as HashMap;
+
+/// Known answers keyed by `(year, day, part)`.
+///
+/// The file has one answer per line as `year day part answer`, blank lines and lines
+/// starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u32, u32, u8), String>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut answers = HashMap::default();
+
+        for (idx, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let malformed = || format!("line {}: expected `year day part answer`", idx + 1);
+            let mut fields = line.split_whitespace();
+            let mut number = || fields.next().and_then(|f| f.parse::<u32>().ok());
+
+            let (Some(year), Some(day), Some(part @ (1 | 2))) = (number(), number(), number())
+            else {
+                return Err(malformed());
+            };
+            let (Some(answer), None) = (fields.next(), fields.next()) else {
+                return Err(malformed());
+            };
+
+            if answers
+                .insert((year, day, part as u8), answer.to_string())
+                .is_some()
+            {
+                return Err(format!(
+                    "line {}: duplicate answer for {year} day {day} part {part}",
+                    idx + 1
+                ));
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u8) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    pub fn check(&self, year: u32, day: u32, part: u8, answer: &str) -> Verdict {
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE: &str = "# year day part answer
+2025 1 1 1165
+
+2025 1 2 6496
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(Some("1165"), answers.get(2025, 1, 1));
+        assert_eq!(Some("6496"), answers.get(2025, 1, 2));
+        assert_eq!(None, answers.get(2025, 2, 1));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(Verdict::Pass, answers.check(2025, 1, 1, "1165"));
+        assert_eq!(Verdict::Fail, answers.check(2025, 1, 1, "1166"));
+        assert_eq!(Verdict::Missing, answers.check(2025, 3, 1, "1"));
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(Answers::parse("2025 1 3 10").is_err());
+        assert!(Answers::parse("2025 1 1").is_err());
+        assert!(Answers::parse("2025 1 1 10 11").is_err());
+        assert!(Answers::parse("2025 1 1 10\n2025 1 1 11").is_err());
+    }
+}
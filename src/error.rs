@@ -0,0 +1,103 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Position in the puzzle input where parsing failed and what was expected there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// `(year, day)` of the puzzle, filled in by the registry.
+    pub puzzle: Option<(u32, u32)>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based byte column.
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error pointing at `token`, which must be a subslice of `input`.
+    ///
+    /// Line and column are only computed here, so parsers can pass sections or lines
+    /// around freely and still report positions relative to the whole file. A `token`
+    /// outside of `input` points at its end.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+
+        let before = &input.as_bytes()[..offset];
+        let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+        let line_start = before
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |idx| idx + 1);
+
+        ParseError {
+            puzzle: None,
+            line,
+            column: offset - line_start + 1,
+            expected: expected.into(),
+        }
+    }
+
+    /// Error at the very end of `input`, for sections or lines that are missing.
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    pub fn in_puzzle(mut self, year: u32, day: u32) -> Self {
+        self.puzzle = Some((year, day));
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((year, day)) = self.puzzle {
+            write!(f, "{year} day {day}: ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token` with `FromStr`, reporting its position in `input` on failure.
+pub fn parse_at<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "1-3\n4-x\n";
+
+    #[test]
+    fn test_position() {
+        let token = &INPUT[6..7];
+        let err = ParseError::at(INPUT, token, "number").in_puzzle(2025, 5);
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!(
+            "2025 day 5: line 2, column 3: expected number",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_end_of_input() {
+        let err = ParseError::end_of_input(INPUT, "blank line");
+        assert_eq!((3, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_parse_at() {
+        assert_eq!(Ok(4u32), parse_at(INPUT, &INPUT[4..5], "number"));
+        assert!(parse_at::<u32>(INPUT, &INPUT[6..7], "number").is_err());
+    }
+}
@@ -0,0 +1,331 @@
+//! Random but well-formed puzzle inputs, for stress tests and benchmarks at any size.
+//!
+//! `size` is the number of input lines, or the side of the grid for grid puzzles.
+
+use std::fmt::Write;
+
+use crate::util::rng::Rng;
+
+pub struct Generator {
+    pub year: u32,
+    pub day: u32,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(&mut Rng::new(seed), size.max(1))
+    }
+}
+
+/// Every day with a generator, sorted by year and day.
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        year: 2025,
+        day: 1,
+        generate: dial_rotations,
+    },
+    Generator {
+        year: 2025,
+        day: 2,
+        generate: id_ranges,
+    },
+    Generator {
+        year: 2025,
+        day: 4,
+        generate: paper_rolls,
+    },
+    Generator {
+        year: 2025,
+        day: 5,
+        generate: fresh_ingredients,
+    },
+    Generator {
+        year: 2025,
+        day: 7,
+        generate: splitters,
+    },
+    Generator {
+        year: 2025,
+        day: 9,
+        generate: red_tiles,
+    },
+    Generator {
+        year: 2025,
+        day: 10,
+        generate: machines,
+    },
+    Generator {
+        year: 2025,
+        day: 11,
+        generate: devices,
+    },
+];
+
+pub fn get(year: u32, day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.year == year && g.day == day)
+}
+
+/// 2025 day 1: `L68` / `R48` rotations.
+fn dial_rotations(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let direction = if rng.chance(1, 2) { 'L' } else { 'R' };
+        let _ = writeln!(input, "{direction}{}", rng.range(1..=999));
+    }
+    input
+}
+
+/// 2025 day 2: one line of comma separated `start-end` id ranges.
+fn id_ranges(rng: &mut Rng, size: usize) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.range(1..=10) as u32;
+            let start = rng.range(1..=10_i64.pow(digits));
+            let end = start + rng.range(0..=10_i64.pow(digits.min(6)));
+            format!("{start}-{end}")
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+/// 2025 day 4: square grid of `@` paper rolls.
+fn paper_rolls(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        input.extend((0..size).map(|_| if rng.chance(3, 5) { '@' } else { '.' }));
+        input.push('\n');
+    }
+    input
+}
+
+/// 2025 day 5: `start-end` fresh ranges, a blank line, then ingredient ids.
+fn fresh_ingredients(rng: &mut Rng, size: usize) -> String {
+    const MAX_ID: i64 = 1 << 48;
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let start = rng.range(1..=MAX_ID);
+        let end = start + rng.range(0..=MAX_ID / 1000);
+        let _ = writeln!(input, "{start}-{end}");
+    }
+    input.push('\n');
+    for _ in 0..size {
+        let _ = writeln!(input, "{}", rng.range(1..=MAX_ID));
+    }
+    input
+}
+
+/// 2025 day 7: `S` in the top row, splitters `^` on every other row below it.
+fn splitters(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(3);
+    let start = rng.range(1..=width as i64 - 2) as usize;
+
+    let mut input = String::with_capacity(size * (width + 1));
+    for row in 0..size {
+        for column in 0..width {
+            let tile = if row == 0 && column == start {
+                'S'
+            } else if row % 2 == 0
+                && row > 0
+                && (1..width - 1).contains(&column)
+                && rng.chance(1, 4)
+            {
+                '^'
+            } else {
+                '.'
+            };
+            input.push(tile);
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// 2025 day 9: corners of an x-monotone rectilinear polygon, listed in order.
+///
+/// Walks a random top profile left to right and a random bottom profile back, the two never
+/// touch so the polygon is simple, and neighbouring corners always share a row or column.
+fn red_tiles(rng: &mut Rng, size: usize) -> String {
+    const MID: i64 = 50_000;
+
+    let columns = (size / 4).max(1) + 1;
+    let mut xs: Vec<i64> = Vec::with_capacity(columns);
+    let mut x = rng.range(0..=1000);
+    for _ in 0..columns {
+        xs.push(x);
+        x += rng.range(1..=1000);
+    }
+
+    // One height per strip between consecutive columns, never equal to the previous one
+    let profile = |rng: &mut Rng, low: i64, high: i64| {
+        let mut heights: Vec<i64> = Vec::with_capacity(columns - 1);
+        for _ in 0..columns - 1 {
+            let mut h = rng.range(low..=high);
+            while heights.last() == Some(&h) {
+                h = rng.range(low..=high);
+            }
+            heights.push(h);
+        }
+        heights
+    };
+    let top = profile(rng, MID + 1, 2 * MID);
+    let bottom = profile(rng, 0, MID - 1);
+
+    let mut corners = Vec::with_capacity(4 * columns);
+    for (i, &h) in top.iter().enumerate() {
+        corners.push((xs[i], h));
+        corners.push((xs[i + 1], h));
+    }
+    for (i, &h) in bottom.iter().enumerate().rev() {
+        corners.push((xs[i + 1], h));
+        corners.push((xs[i], h));
+    }
+    debug_assert!(corners.windows(2).all(|w| w[0] != w[1]));
+
+    let mut input = String::new();
+    for (x, y) in corners {
+        let _ = writeln!(input, "{x},{y}");
+    }
+    input
+}
+
+/// 2025 day 10: machines whose target lights and joltages come from actual button presses,
+/// so both parts always have a solution.
+fn machines(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let lights = rng.range(2..=10) as usize;
+        let buttons: Vec<Vec<usize>> = (0..rng.range(2..=12))
+            .map(|_| {
+                let mut wiring: Vec<usize> = (0..lights).filter(|_| rng.chance(2, 5)).collect();
+                if wiring.is_empty() {
+                    wiring.push(rng.below(lights as u64) as usize);
+                }
+                wiring
+            })
+            .collect();
+
+        let mut diagram = vec![false; lights];
+        let mut joltage = vec![0; lights];
+        for wiring in &buttons {
+            if rng.chance(1, 2) {
+                wiring.iter().for_each(|&light| diagram[light] ^= true);
+            }
+            let presses = rng.range(0..=40);
+            wiring.iter().for_each(|&light| joltage[light] += presses);
+        }
+
+        input.push('[');
+        input.extend(diagram.iter().map(|&on| if on { '#' } else { '.' }));
+        input.push(']');
+        for wiring in &buttons {
+            let wiring: Vec<String> = wiring.iter().map(usize::to_string).collect();
+            let _ = write!(input, " ({})", wiring.join(","));
+        }
+        let joltage: Vec<String> = joltage.iter().map(i64::to_string).collect();
+        let _ = writeln!(input, " {{{}}}", joltage.join(","));
+    }
+    input
+}
+
+/// 2025 day 11: a DAG of `name: outputs` lines containing `you`, `svr`, `fft`, `dac` and `out`.
+///
+/// Edges that would push the number of paths to `out` over 2^40 are dropped, so neither part
+/// can overflow whatever the size.
+fn devices(rng: &mut Rng, size: usize) -> String {
+    const MAX_PATHS: u64 = 1 << 40;
+    const SPECIAL: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+    let mut names: Vec<String> = SPECIAL.iter().map(|s| s.to_string()).collect();
+    while names.len() < size.max(1) + SPECIAL.len() {
+        let name: String = (0..3)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    // Topological order: `svr` and `you` early, `fft` and `dac` somewhere after them, `out` last
+    let out = names.swap_remove(4);
+    let (mut specials, mut others): (Vec<String>, Vec<String>) = names
+        .into_iter()
+        .partition(|name| SPECIAL.contains(&name.as_str()));
+    for i in (1..others.len()).rev() {
+        others.swap(i, rng.below(i as u64 + 1) as usize);
+    }
+    let mut order = Vec::with_capacity(others.len() + 5);
+    order.extend(specials.drain(..2));
+    for (i, name) in others.into_iter().enumerate() {
+        if i % 3 == 0 && !specials.is_empty() {
+            order.push(specials.remove(rng.below(specials.len() as u64) as usize));
+        }
+        order.push(name);
+    }
+    order.append(&mut specials);
+    order.push(out);
+
+    // Built from the end, paths[i] is the number of paths from order[i] to `out`
+    let n = order.len();
+    let mut paths = vec![0u64; n];
+    paths[n - 1] = 1;
+    let mut outputs: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for i in (0..n - 1).rev() {
+        for _ in 0..rng.range(1..=4) {
+            let next = i + 1 + rng.below(((n - i - 1) as u64).min(8)) as usize;
+            if outputs[i].contains(&next) || paths[i] + paths[next] > MAX_PATHS {
+                continue;
+            }
+            outputs[i].push(next);
+            paths[i] += paths[next];
+        }
+        // Every device leads somewhere, `out` is always a safe choice
+        if outputs[i].is_empty() {
+            outputs[i].push(n - 1);
+            paths[i] += 1;
+        }
+    }
+
+    let mut input = String::new();
+    for i in 0..n - 1 {
+        let targets: Vec<&str> = outputs[i].iter().map(|&j| order[j].as_str()).collect();
+        let _ = writeln!(input, "{}: {}", order[i], targets.join(" "));
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_reproducible() {
+        for generator in GENERATORS {
+            assert_eq!(generator.generate(20, 3), generator.generate(20, 3));
+        }
+    }
+
+    #[test]
+    fn test_inputs_solve() {
+        for generator in GENERATORS {
+            let puzzle = registry::get(generator.year, generator.day).unwrap();
+            for seed in 0..5 {
+                let input = generator.generate(30, seed);
+                let parsed = puzzle.parse(&input).unwrap_or_else(|err| {
+                    panic!("seed {seed} generated an invalid input: {err}\n{input}")
+                });
+
+                let [part_1, part_2] = puzzle.implemented;
+                if part_1 {
+                    parsed.part_1();
+                }
+                // 2025 day 10 part 2 hands the equations to z3, which is left to the runner
+                if part_2 && (generator.year, generator.day) != (2025, 10) {
+                    parsed.part_2();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Puzzle inputs cached as `inputs/yearYYYY/dayNN.txt`, downloaded on demand.
+
+use std::{
+    env, error, fmt, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use ureq::Agent;
+
+/// Directory holding the cached inputs, relative to the crate root.
+pub const INPUTS_DIR: &str = "inputs";
+/// Environment variable holding the value of the `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the server, for mirrors and tests.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Cache path of an input below `root`, `root/yearYYYY/dayNN.txt`.
+pub fn cache_path(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join(format!("year{year}"))
+        .join(format!("day{day:02}.txt"))
+}
+
+/// Cache path of an input below [`INPUTS_DIR`].
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    cache_path(Path::new(INPUTS_DIR), year, day)
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// [`SESSION_VAR`] is not set.
+    MissingSession,
+    /// The server could not be reached or did not answer with the input.
+    Http(ureq::Error),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(f, "{SESSION_VAR} is not set"),
+            FetchError::Http(err) => write!(f, "download failed: {err}"),
+            FetchError::Io(err) => write!(f, "could not write input: {err}"),
+        }
+    }
+}
+
+impl error::Error for FetchError {}
+
+impl From<ureq::Error> for FetchError {
+    fn from(err: ureq::Error) -> Self {
+        FetchError::Http(err)
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> Self {
+        FetchError::Io(err)
+    }
+}
+
+/// Whether [`Fetcher::fetch`] had to download the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads missing inputs into a cache directory.
+pub struct Fetcher {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    root: PathBuf,
+}
+
+impl Fetcher {
+    pub fn new(
+        base_url: impl Into<String>,
+        session: impl Into<String>,
+        root: impl Into<PathBuf>,
+    ) -> Self {
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent("aoc-rs input fetcher")
+            .build()
+            .into();
+        Fetcher {
+            agent,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            root: root.into(),
+        }
+    }
+
+    /// Fetcher for [`INPUTS_DIR`] configured from [`SESSION_VAR`] and [`BASE_URL_VAR`].
+    pub fn from_env() -> Result<Self, FetchError> {
+        let session = env::var(SESSION_VAR).map_err(|_| FetchError::MissingSession)?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string());
+        Ok(Fetcher::new(base_url, session, INPUTS_DIR))
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        cache_path(&self.root, year, day)
+    }
+
+    /// Makes sure the input is cached, never downloading one that already is.
+    pub fn fetch(&self, year: u32, day: u32) -> Result<Fetched, FetchError> {
+        let path = self.path(year, day);
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let input = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call()?
+            .body_mut()
+            .read_to_string()?;
+
+        // Written aside and renamed, so an interrupted download is never mistaken for a cached input
+        fs::create_dir_all(path.parent().expect("cache path has a parent"))?;
+        let partial = path.with_extension("part");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)?;
+
+        Ok(Fetched::Downloaded)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        thread,
+    };
+
+    use super::*;
+
+    const SESSION: &str = "53cr3t";
+
+    /// Stand-in for the puzzle server, serves the files below `tests/fetch`, at the path of their
+    /// URL, to requests carrying `SESSION`.
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fetch");
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let head: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map_while(Result::ok)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                let path = head[0].split_whitespace().nth(1).unwrap_or_default();
+                let authorized = head
+                    .iter()
+                    .any(|line| line.eq_ignore_ascii_case(&format!("cookie: session={SESSION}")));
+
+                let fixture = fs::read_to_string(fixtures.join(path.trim_start_matches('/')));
+                let (status, body) = match fixture {
+                    Ok(body) if authorized => ("200 OK", body),
+                    Ok(_) => (
+                        "400 Bad Request",
+                        "Puzzle inputs differ by user.".to_string(),
+                    ),
+                    Err(_) => ("404 Not Found", "404 Not Found".to_string()),
+                };
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-rs-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn test_cache_path() {
+        assert_eq!(
+            PathBuf::from("inputs/year2023/day05.txt"),
+            input_path(2023, 5)
+        );
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let (base_url, requests) = serve();
+        let root = temp_root("fetch");
+        let fetcher = Fetcher::new(base_url, SESSION, &root);
+
+        assert_eq!(Fetched::Downloaded, fetcher.fetch(2015, 1).unwrap());
+        assert_eq!(
+            "(()(()(\n",
+            fs::read_to_string(fetcher.path(2015, 1)).unwrap()
+        );
+        assert_eq!(Fetched::Cached, fetcher.fetch(2015, 1).unwrap());
+        assert_eq!(1, requests.load(Ordering::SeqCst));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, _) = serve();
+        let root = temp_root("errors");
+
+        let fetcher = Fetcher::new(base_url.as_str(), SESSION, &root);
+        assert!(matches!(fetcher.fetch(2015, 2), Err(FetchError::Http(_))));
+
+        let fetcher = Fetcher::new(base_url.as_str(), "wrong", &root);
+        assert!(matches!(fetcher.fetch(2015, 1), Err(FetchError::Http(_))));
+        assert!(!fetcher.path(2015, 1).exists());
+    }
+}
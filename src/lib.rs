@@ -1,3 +1,14 @@
+pub mod answers;
+pub mod error;
+pub mod generate;
+pub mod inputs;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod solution;
+pub mod stats;
+#[cfg(test)]
+pub mod testing;
 pub mod util;
 pub mod year2023;
 pub mod year2025;
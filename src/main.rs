@@ -1,26 +1,888 @@
-use std::{fs::read_to_string, time::Instant};
+use std::{
+    env,
+    fs::{self, read_to_string},
+    hint::black_box,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    time::{Duration, Instant},
+};
 
-use aoc_rs::year2025;
+use aoc_rs::{
+    answers::{Answers, Verdict},
+    generate,
+    inputs::{self, Fetched, Fetcher},
+    registry::{self, Puzzle},
+    report::{self, Row, Status},
+    scaffold,
+    stats::Stats,
+};
 
-fn main() {
-    let data = read_to_string("inputs/year2025/day11.txt").unwrap();
+const USAGE: &str = "Usage: aoc-rs [fetch|new|gen] [--year YYYY] [--day N] [--part 1|2|all] [--list] [--check [ANSWERS]] [--bench N] [--format text|json] [--size N] [--seed N] [--output PATH]";
+const ANSWERS_PATH: &str = "inputs/answers.txt";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Part {
+    One,
+    Two,
+    All,
+}
+
+impl Part {
+    fn runs(self, part: Part) -> bool {
+        self == Part::All || self == part
+    }
+
+    fn as_arg(self) -> &'static str {
+        match self {
+            Part::One => "1",
+            Part::Two => "2",
+            Part::All => "all",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Subcommand {
+    Solve,
+    /// Download missing inputs.
+    Fetch,
+    /// Scaffold the module of a new day.
+    New,
+    /// Write a random input of `--size` from `--seed`.
+    Gen,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct Args {
+    year: Option<u32>,
+    day: Option<u32>,
+    part: Part,
+    list: bool,
+    /// Answers file to verify against, set by `--check`.
+    check: Option<PathBuf>,
+    /// Number of timed iterations of every phase, set by `--bench`.
+    bench: Option<usize>,
+    format: Format,
+    /// Internal, set when a multi-day run spawned this process to solve a single day.
+    worker: bool,
+    subcommand: Subcommand,
+    size: usize,
+    seed: u64,
+    /// Where `gen` writes the input, stdout if unset.
+    output: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let years = registry::years();
+    let mut args = Args {
+        year: None,
+        day: None,
+        part: Part::All,
+        list: false,
+        check: None,
+        bench: None,
+        format: Format::Text,
+        worker: false,
+        subcommand: Subcommand::Solve,
+        size: 100,
+        seed: 0,
+        output: None,
+    };
 
-    let data = year2025::day11::parse(&data);
+    let mut argv = env::args().skip(1).peekable();
+    if let Some(subcommand) = argv.next_if(|arg| !arg.starts_with('-')) {
+        args.subcommand = match subcommand.as_str() {
+            "fetch" => Subcommand::Fetch,
+            "new" => Subcommand::New,
+            "gen" => Subcommand::Gen,
+            _ => return Err(format!("unknown subcommand: {subcommand}")),
+        };
+    }
+    while let Some(flag) = argv.next() {
+        match flag.as_str() {
+            "--list" => {
+                args.list = true;
+                continue;
+            }
+            "--worker" => {
+                args.worker = true;
+                continue;
+            }
+            "--check" => {
+                let path = argv.next_if(|next| !next.starts_with('-'));
+                args.check = Some(path.map_or_else(|| PathBuf::from(ANSWERS_PATH), PathBuf::from));
+                continue;
+            }
+            _ => {}
+        }
 
+        let value = argv
+            .next()
+            .ok_or_else(|| format!("missing value for {flag}"))?;
+
+        match flag.as_str() {
+            "--year" | "-y" => {
+                let year = value
+                    .parse()
+                    .map_err(|_| format!("invalid year: {value}"))?;
+                // Inputs of years without solutions can still be fetched or scaffolded
+                if args.subcommand == Subcommand::Solve && !years.contains(&year) {
+                    return Err(format!("no solutions for year {year}"));
+                }
+                args.year = Some(year);
+            }
+            "--day" | "-d" => {
+                let day = value.parse().map_err(|_| format!("invalid day: {value}"))?;
+                if !(1..=25).contains(&day) {
+                    return Err(format!("day must be in 1..=25, got {day}"));
+                }
+                args.day = Some(day);
+            }
+            "--part" | "-p" => {
+                args.part = match value.as_str() {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    "all" => Part::All,
+                    _ => return Err(format!("invalid part: {value}")),
+                }
+            }
+            "--bench" => {
+                let iterations = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("invalid number of iterations: {value}"))?;
+                args.bench = Some(iterations);
+            }
+            "--format" => {
+                args.format = match value.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(format!("invalid format: {value}")),
+                }
+            }
+            "--size" => {
+                args.size = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("invalid size: {value}"))?;
+            }
+            "--seed" => {
+                args.seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid seed: {value}"))?;
+            }
+            "--output" | "-o" => args.output = Some(PathBuf::from(value)),
+            _ => return Err(format!("unknown argument: {flag}")),
+        }
+    }
+
+    if args.format == Format::Json && (args.list || args.bench.is_some()) {
+        return Err("--format json only applies to solving and --check".to_string());
+    }
+
+    if args.subcommand == Subcommand::New && args.day.is_none() {
+        return Err("new needs the --day to scaffold".to_string());
+    }
+
+    if args.subcommand == Subcommand::Gen && args.day.is_none() {
+        return Err("gen needs the --day to generate an input for".to_string());
+    }
+
+    if args.worker && args.day.is_none() {
+        return Err("--worker solves a single --day".to_string());
+    }
+
+    if args.day.is_some() && args.year.is_none() {
+        args.year = years.last().copied();
+    }
+
+    Ok(args)
+}
+
+struct Solved {
+    answer: String,
+    elapsed: Duration,
+}
+
+fn timed(enabled: bool, f: impl FnOnce() -> String) -> Option<Solved> {
+    if !enabled {
+        return None;
+    }
     let start = Instant::now();
-    let solution_part_1 = year2025::day11::part_1(&data);
-    let duration_part_1 = start.elapsed();
+    let answer = f();
+    Some(Solved {
+        answer,
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Result of a part that ran, `Err` holds the message of a part that panicked.
+type Outcome = Result<Solved, String>;
+
+/// Runs the requested parts of a day, skipping the ones that are not implemented.
+///
+/// When `isolated`, the day runs in a child process so that a panic, which aborts with the
+/// release profile, only loses that day. Fails with the parse error message.
+fn solve(
+    puzzle: &Puzzle,
+    input: &str,
+    part: Part,
+    isolated: bool,
+) -> Result<[Option<Outcome>; 2], String> {
+    if isolated {
+        return solve_in_worker(puzzle, part);
+    }
+
+    let [part_1, part_2] = puzzle.implemented;
+    let parsed = puzzle.parse(input).map_err(|err| err.to_string())?;
+    Ok([
+        timed(part_1 && part.runs(Part::One), || parsed.part_1()).map(Ok),
+        timed(part_2 && part.runs(Part::Two), || parsed.part_2()).map(Ok),
+    ])
+}
+
+/// Entry point of `--worker`, reports every part as soon as it is solved so the parent keeps
+/// the parts that finished before a panic.
+///
+/// Lines are `solved <part> <nanos> <answer>` or `invalid <message>`, separated by tabs.
+fn worker(args: &Args) {
+    let puzzle = select(args, false)[0];
+    let Some(input) = read_input(puzzle, args) else {
+        return;
+    };
+
+    let parsed = match puzzle.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            println!("invalid\t{err}");
+            return;
+        }
+    };
+
+    let [part_1, part_2] = puzzle.implemented;
+    if let Some(Solved { answer, elapsed }) =
+        timed(part_1 && args.part.runs(Part::One), || parsed.part_1())
+    {
+        println!("solved\t1\t{}\t{answer}", elapsed.as_nanos());
+    }
+    if let Some(Solved { answer, elapsed }) =
+        timed(part_2 && args.part.runs(Part::Two), || parsed.part_2())
+    {
+        println!("solved\t2\t{}\t{answer}", elapsed.as_nanos());
+    }
+}
+
+fn solve_in_worker(puzzle: &Puzzle, part: Part) -> Result<[Option<Outcome>; 2], String> {
+    let output = env::current_exe().and_then(|exe| {
+        Command::new(exe)
+            .args(["--worker", "--part", part.as_arg()])
+            .args(["--year", &puzzle.year.to_string()])
+            .args(["--day", &puzzle.day.to_string()])
+            .stdin(Stdio::null())
+            .output()
+    });
+
+    let mut solved = [None, None];
+    let failure = match output {
+        Ok(output) => {
+            for line in String::from_utf8_lossy(&output.stdout).lines() {
+                let mut fields = line.splitn(4, '\t');
+                match (fields.next(), fields.next(), fields.next(), fields.next()) {
+                    (Some("invalid"), Some(message), None, None) => return Err(message.to_string()),
+                    (Some("solved"), Some(part), Some(nanos), Some(answer)) => {
+                        let (Ok(part @ 1..=2), Ok(nanos)) = (part.parse::<usize>(), nanos.parse())
+                        else {
+                            continue;
+                        };
+                        solved[part - 1] = Some(Ok(Solved {
+                            answer: answer.to_string(),
+                            elapsed: Duration::from_nanos(nanos),
+                        }));
+                    }
+                    _ => {}
+                }
+            }
+            panic_message(&String::from_utf8_lossy(&output.stderr))
+                .unwrap_or_else(|| format!("worker exited with {}", output.status))
+        }
+        Err(err) => format!("could not start worker: {err}"),
+    };
+
+    // Requested parts the worker never reported are the ones that crashed it
+    let requested = [part.runs(Part::One), part.runs(Part::Two)];
+    for ((solution, implemented), requested) in
+        solved.iter_mut().zip(puzzle.implemented).zip(requested)
+    {
+        if implemented && requested && solution.is_none() {
+            *solution = Some(Err(failure.clone()));
+        }
+    }
+    Ok(solved)
+}
+
+/// Message of the default panic hook output, the lines between `panicked at` and the notes.
+fn panic_message(stderr: &str) -> Option<String> {
+    let mut lines = stderr
+        .lines()
+        .skip_while(|line| !line.contains("panicked at"));
+    let location = lines.next()?;
+    let message: Vec<&str> = lines
+        .take_while(|line| !line.starts_with("note:") && !line.starts_with("stack backtrace:"))
+        .collect();
+    if message.is_empty() {
+        Some(location.to_string())
+    } else {
+        Some(message.join("\n"))
+    }
+}
+
+fn list() {
+    let mark = |done: bool| if done { "done" } else { "todo" };
     println!(
-        "Day 10 - Part 1 solution {} - elapsed {:#?}",
-        solution_part_1, duration_part_1
+        "{:>4} | {:>3} | {:>6} | {:>6}",
+        "Year", "Day", "Part 1", "Part 2"
     );
+    for puzzle in registry::PUZZLES {
+        let [part_1, part_2] = puzzle.implemented;
+        println!(
+            "{:>4} | {:>3} | {:>6} | {:>6}",
+            puzzle.year,
+            puzzle.day,
+            mark(part_1),
+            mark(part_2)
+        );
+    }
+}
 
-    // let input = year2025::day06::parse_part2(&data);
-    let start = Instant::now();
-    let solution_part_2 = year2025::day11::part_2(&data);
-    let duration_part_2 = start.elapsed();
+/// Puzzles picked by `--year` and `--day`, every year when `all_years` is set and no year is given.
+fn select(args: &Args, all_years: bool) -> Vec<&'static Puzzle> {
+    match (args.year, args.day) {
+        (Some(year), Some(day)) => match registry::get(year, day) {
+            Some(puzzle) => vec![puzzle],
+            None => {
+                eprintln!("day {day} of {year} is not implemented");
+                process::exit(1);
+            }
+        },
+        (Some(year), None) => registry::year(year).collect(),
+        (None, _) if all_years => registry::PUZZLES.iter().collect(),
+        (None, _) => {
+            let latest = *registry::years().last().unwrap();
+            registry::year(latest).collect()
+        }
+    }
+}
+
+/// Reads the input of a puzzle, a missing file is only fatal when a single day was requested.
+fn read_input(puzzle: &Puzzle, args: &Args) -> Option<String> {
+    let path = puzzle.input_path();
+    match read_to_string(&path) {
+        Ok(input) => Some(input),
+        Err(err) => {
+            if args.day.is_some() {
+                eprintln!("could not read {}: {err}", path.display());
+                process::exit(1);
+            }
+            None
+        }
+    }
+}
+
+fn summary(args: &Args) {
+    let puzzles = select(args, false);
+
+    println!("Year {}", puzzles[0].year);
     println!(
-        "Day 10 - Part 2 solution {} - elapsed {:#?}",
-        solution_part_2, duration_part_2
+        "{:>4} | {:>20} | {:>12} | {:>20} | {:>12}",
+        "Day", "Part 1", "Elapsed", "Part 2", "Elapsed"
     );
+
+    let isolated = puzzles.len() > 1;
+    let mut total = Duration::ZERO;
+    for puzzle in puzzles {
+        let Some(input) = read_input(puzzle, args) else {
+            continue;
+        };
+
+        let solved = match solve(puzzle, &input, args.part, isolated) {
+            Ok(solved) => solved,
+            Err(err) => {
+                eprintln!("invalid input: {err}");
+                if args.day.is_some() {
+                    process::exit(1);
+                }
+                let cell = |part| if args.part.runs(part) { "invalid" } else { "-" };
+                println!(
+                    "{:>4} | {:>20} | {:>12} | {:>20} | {:>12}",
+                    puzzle.day,
+                    cell(Part::One),
+                    "-",
+                    cell(Part::Two),
+                    "-"
+                );
+                continue;
+            }
+        };
+
+        let mut cells = Vec::with_capacity(4);
+        for ((part, solution), implemented) in (1..=2).zip(&solved).zip(puzzle.implemented) {
+            match solution {
+                Some(Ok(Solved { answer, elapsed })) => {
+                    total += *elapsed;
+                    cells.push(answer.clone());
+                    cells.push(format!("{elapsed:?}"));
+                }
+                Some(Err(message)) => {
+                    eprintln!(
+                        "{} day {} part {part} panicked: {message}",
+                        puzzle.year, puzzle.day
+                    );
+                    cells.push("panicked".to_string());
+                    cells.push("-".to_string());
+                }
+                None => {
+                    let skipped = if implemented { "-" } else { "todo" };
+                    cells.push(skipped.to_string());
+                    cells.push("-".to_string());
+                }
+            }
+        }
+
+        println!(
+            "{:>4} | {:>20} | {:>12} | {:>20} | {:>12}",
+            puzzle.day, cells[0], cells[1], cells[2], cells[3]
+        );
+    }
+
+    println!("Total elapsed {total:?}");
+}
+
+/// Times `f` over `iterations` runs.
+fn sample<T>(iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    let mut samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&mut samples).expect("at least one iteration")
+}
+
+/// Times parse, part 1 and part 2 of every selected day separately.
+fn bench(args: &Args, iterations: usize) {
+    println!(
+        "{:>4} | {:>3} | {:>6} | {:>12} | {:>12} | {:>12} | {:>12}",
+        "Year", "Day", "Phase", "Min", "Median", "Mean", "Stddev"
+    );
+
+    for puzzle in select(args, false) {
+        let Some(input) = read_input(puzzle, args) else {
+            continue;
+        };
+
+        let parsed = match puzzle.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("invalid input: {err}");
+                if args.day.is_some() {
+                    process::exit(1);
+                }
+                continue;
+            }
+        };
+
+        let [part_1, part_2] = puzzle.implemented;
+        let mut phases = vec![("parse", sample(iterations, || puzzle.parse(&input)))];
+        if part_1 && args.part.runs(Part::One) {
+            phases.push(("part 1", sample(iterations, || parsed.part_1())));
+        }
+        if part_2 && args.part.runs(Part::Two) {
+            phases.push(("part 2", sample(iterations, || parsed.part_2())));
+        }
+
+        for (phase, stats) in phases {
+            println!(
+                "{:>4} | {:>3} | {:>6} | {:>12} | {:>12} | {:>12} | {:>12}",
+                puzzle.year,
+                puzzle.day,
+                phase,
+                format!("{:?}", stats.min),
+                format!("{:?}", stats.median),
+                format!("{:?}", stats.mean),
+                format!("{:?}", stats.stddev)
+            );
+        }
+    }
+}
+
+/// Verifies every computed answer, returns `false` if any of them is wrong.
+fn check(args: &Args, answers: &Answers) -> bool {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    println!(
+        "{:>4} | {:>3} | {:>4} | {:>7} | {:>20} | {:>20}",
+        "Year", "Day", "Part", "Status", "Answer", "Expected"
+    );
+
+    let puzzles = select(args, true);
+    let isolated = puzzles.len() > 1;
+    for puzzle in puzzles {
+        let expected = |part| answers.get(puzzle.year, puzzle.day, part).unwrap_or("-");
+        let requested: Vec<u8> = [(1, Part::One), (2, Part::Two)]
+            .into_iter()
+            .filter(|&(part, p)| puzzle.implemented[part as usize - 1] && args.part.runs(p))
+            .map(|(part, _)| part)
+            .collect();
+
+        // Without an input, parts with a known answer fail rather than go unchecked
+        let Some(input) = read_input(puzzle, args) else {
+            eprintln!("could not read {}", puzzle.input_path().display());
+            for &part in &requested {
+                let status = if answers.get(puzzle.year, puzzle.day, part).is_some() {
+                    failed += 1;
+                    "FAIL"
+                } else {
+                    missing += 1;
+                    "MISSING"
+                };
+                println!(
+                    "{:>4} | {:>3} | {:>4} | {:>7} | {:>20} | {:>20}",
+                    puzzle.year,
+                    puzzle.day,
+                    part,
+                    status,
+                    "-",
+                    expected(part)
+                );
+            }
+            continue;
+        };
+
+        let solved = match solve(puzzle, &input, args.part, isolated) {
+            Ok(solved) => solved,
+            Err(err) => {
+                // Every requested part is lost when the input does not parse
+                eprintln!("invalid input: {err}");
+                for &part in &requested {
+                    failed += 1;
+                    println!(
+                        "{:>4} | {:>3} | {:>4} | {:>7} | {:>20} | {:>20}",
+                        puzzle.year,
+                        puzzle.day,
+                        part,
+                        "INVALID",
+                        "-",
+                        expected(part)
+                    );
+                }
+                continue;
+            }
+        };
+        for (part, solution) in (1..=2).zip(&solved) {
+            let answer = match solution {
+                Some(Ok(Solved { answer, .. })) => answer,
+                Some(Err(message)) => {
+                    eprintln!(
+                        "{} day {} part {part} panicked: {message}",
+                        puzzle.year, puzzle.day
+                    );
+                    failed += 1;
+                    println!(
+                        "{:>4} | {:>3} | {:>4} | {:>7} | {:>20} | {:>20}",
+                        puzzle.year,
+                        puzzle.day,
+                        part,
+                        "PANIC",
+                        "-",
+                        expected(part)
+                    );
+                    continue;
+                }
+                None => continue,
+            };
+
+            let verdict = answers.check(puzzle.year, puzzle.day, part, answer);
+            let status = match verdict {
+                Verdict::Pass => {
+                    passed += 1;
+                    "PASS"
+                }
+                Verdict::Fail => {
+                    failed += 1;
+                    "FAIL"
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    "MISSING"
+                }
+            };
+            println!(
+                "{:>4} | {:>3} | {:>4} | {:>7} | {:>20} | {:>20}",
+                puzzle.year,
+                puzzle.day,
+                part,
+                status,
+                answer,
+                expected(part)
+            );
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    failed == 0
+}
+
+/// Prints one JSON row per requested part, compared against `answers` when given.
+/// Returns `false` if any answer is wrong, any input is invalid or missing, or any part panicked.
+/// When checking, a missing input only fails the parts that have an expected answer.
+fn json(args: &Args, answers: Option<&Answers>) -> bool {
+    let mut rows = Vec::new();
+
+    for puzzle in select(args, answers.is_some()) {
+        let row = |part: u8, status: Status| Row {
+            year: puzzle.year,
+            day: puzzle.day,
+            part,
+            status,
+            answer: None,
+            elapsed: None,
+            expected: answers
+                .and_then(|answers| answers.get(puzzle.year, puzzle.day, part))
+                .map(str::to_string),
+            error: None,
+        };
+        let requested: Vec<u8> = [(1, Part::One), (2, Part::Two)]
+            .into_iter()
+            .filter(|&(_, part)| args.part.runs(part))
+            .map(|(part, _)| part)
+            .collect();
+
+        let path = puzzle.input_path();
+        let input = match read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                for &part in &requested {
+                    rows.push(Row {
+                        error: Some(format!("could not read {}: {err}", path.display())),
+                        ..row(part, Status::MissingInput)
+                    });
+                }
+                continue;
+            }
+        };
+
+        // Always in a worker, so that a panic still ends up as a row
+        match solve(puzzle, &input, args.part, true) {
+            Ok(solved) => {
+                for part in requested {
+                    let (answer, elapsed) = match &solved[part as usize - 1] {
+                        Some(Ok(Solved { answer, elapsed })) => (answer, elapsed),
+                        Some(Err(message)) => {
+                            rows.push(Row {
+                                error: Some(message.clone()),
+                                ..row(part, Status::Panicked)
+                            });
+                            continue;
+                        }
+                        None => {
+                            rows.push(row(part, Status::Unimplemented));
+                            continue;
+                        }
+                    };
+                    let verdict =
+                        answers.map(|answers| answers.check(puzzle.year, puzzle.day, part, answer));
+                    let status = if verdict == Some(Verdict::Fail) {
+                        Status::Mismatch
+                    } else {
+                        Status::Ok
+                    };
+                    rows.push(Row {
+                        answer: Some(answer.clone()),
+                        elapsed: Some(*elapsed),
+                        ..row(part, status)
+                    });
+                }
+            }
+            Err(err) => {
+                for part in requested {
+                    rows.push(Row {
+                        error: Some(err.to_string()),
+                        ..row(part, Status::InvalidInput)
+                    });
+                }
+            }
+        }
+    }
+
+    println!("{}", report::to_json(&rows));
+    rows.iter().all(|row| match row.status {
+        Status::Mismatch | Status::InvalidInput | Status::Panicked => false,
+        Status::MissingInput => answers.is_some() && row.expected.is_none(),
+        Status::Ok | Status::Unimplemented => true,
+    })
+}
+
+/// Downloads the missing inputs of `--year` and `--day`, or of every solved day.
+/// Returns `false` if any download failed.
+fn fetch(args: &Args) -> bool {
+    let days: Vec<(u32, u32)> = match (args.year, args.day) {
+        (Some(year), Some(day)) => vec![(year, day)],
+        _ => select(args, true)
+            .iter()
+            .map(|puzzle| (puzzle.year, puzzle.day))
+            .collect(),
+    };
+
+    // The session is only needed once something has to be downloaded
+    let missing = days
+        .iter()
+        .any(|&(year, day)| !inputs::input_path(year, day).exists());
+    let fetcher = match Fetcher::from_env() {
+        Ok(fetcher) => Some(fetcher),
+        Err(err) if missing => {
+            eprintln!("{err}");
+            return false;
+        }
+        Err(_) => None,
+    };
+
+    let mut ok = true;
+    for (year, day) in days {
+        let path = inputs::input_path(year, day);
+        let fetched = match &fetcher {
+            Some(fetcher) => fetcher.fetch(year, day),
+            None => Ok(Fetched::Cached),
+        };
+        match fetched {
+            Ok(Fetched::Cached) => println!("{year} day {day}: cached at {}", path.display()),
+            Ok(Fetched::Downloaded) => {
+                println!("{year} day {day}: downloaded to {}", path.display())
+            }
+            Err(err) => {
+                eprintln!("{year} day {day}: {err}");
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+/// Scaffolds `--day` of `--year`, run from the crate root.
+fn new(args: &Args) {
+    let (Some(year), Some(day)) = (args.year, args.day) else {
+        unreachable!("--day is required and defaults the year");
+    };
+
+    match scaffold::scaffold(Path::new("."), year, day) {
+        Ok(touched) => {
+            for path in touched {
+                println!("wrote {}", path.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("could not scaffold {year} day {day}: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Generates an input for `--day` of `--year` into `--output` or stdout.
+fn gen_input(args: &Args) -> Result<(), String> {
+    let (Some(year), Some(day)) = (args.year, args.day) else {
+        unreachable!("--day is required and defaults the year");
+    };
+    let generator =
+        generate::get(year, day).ok_or_else(|| format!("no generator for {year} day {day}"))?;
+    let input = generator.generate(args.size, args.seed);
+
+    match &args.output {
+        Some(path) => {
+            if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                fs::create_dir_all(dir)
+                    .map_err(|err| format!("could not create {}: {err}", dir.display()))?;
+            }
+            fs::write(path, input)
+                .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+            eprintln!("wrote {}", path.display());
+        }
+        None => print!("{input}"),
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|err| {
+        eprintln!("{err}\n{USAGE}");
+        process::exit(2);
+    });
+
+    if args.list {
+        list();
+        return;
+    }
+
+    if args.worker {
+        worker(&args);
+        return;
+    }
+
+    match args.subcommand {
+        Subcommand::Solve => {}
+        Subcommand::Fetch => {
+            if !fetch(&args) {
+                process::exit(1);
+            }
+            return;
+        }
+        Subcommand::New => {
+            new(&args);
+            return;
+        }
+        Subcommand::Gen => {
+            if let Err(err) = gen_input(&args) {
+                eprintln!("{err}");
+                process::exit(1);
+            }
+            return;
+        }
+    }
+
+    let answers = args.check.as_ref().map(|path| {
+        read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|file| Answers::parse(&file))
+            .unwrap_or_else(|err| {
+                eprintln!("could not load answers from {}: {err}", path.display());
+                process::exit(2);
+            })
+    });
+
+    if args.format == Format::Json {
+        if !json(&args, answers.as_ref()) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(answers) = &answers {
+        if !check(&args, answers) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(iterations) = args.bench {
+        bench(&args, iterations);
+        return;
+    }
+
+    summary(&args);
 }
@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use crate::{error::ParseError, inputs, solution::Solution, year2023, year2025};
+
+/// Parsed input of some day with its type erased, so every day fits in one table.
+pub trait Parsed {
+    fn part_1(&self) -> String;
+    fn part_2(&self) -> String;
+}
+
+struct Erased<'a, S: Solution>(S::Input<'a>);
+
+impl<S: Solution> Parsed for Erased<'_, S> {
+    fn part_1(&self) -> String {
+        S::part_1(&self.0).to_string()
+    }
+
+    fn part_2(&self) -> String {
+        S::part_2(&self.0).to_string()
+    }
+}
+
+fn erase<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed + '_>, ParseError> {
+    let parsed = S::parse(input)?;
+    Ok(Box::new(Erased::<S>(parsed)))
+}
+
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    /// Whether part 1 and part 2 are solved or still a `todo!()`.
+    pub implemented: [bool; 2],
+    parse: for<'a> fn(&'a str) -> Result<Box<dyn Parsed + 'a>, ParseError>,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution + 'static>(year: u32, day: u32) -> Self {
+        Puzzle {
+            year,
+            day,
+            implemented: S::IMPLEMENTED,
+            parse: erase::<S>,
+        }
+    }
+
+    pub fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        (self.parse)(input).map_err(|err| err.in_puzzle(self.year, self.day))
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        inputs::input_path(self.year, self.day)
+    }
+}
+
+/// Every puzzle module, sorted by year and day.
+pub static PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<year2023::day1::Day1>(2023, 1),
+    Puzzle::new::<year2023::day2::Day2>(2023, 2),
+    Puzzle::new::<year2023::day3::Day3>(2023, 3),
+    Puzzle::new::<year2023::day4::Day4>(2023, 4),
+    Puzzle::new::<year2023::day5::Day5>(2023, 5),
+    Puzzle::new::<year2023::day6::Day6>(2023, 6),
+    Puzzle::new::<year2023::day7::Day7>(2023, 7),
+    Puzzle::new::<year2023::day8::Day8>(2023, 8),
+    Puzzle::new::<year2023::day9::Day9>(2023, 9),
+    Puzzle::new::<year2023::day10::Day10>(2023, 10),
+    Puzzle::new::<year2025::day01::Day01>(2025, 1),
+    Puzzle::new::<year2025::day02::Day02>(2025, 2),
+    Puzzle::new::<year2025::day03::Day03>(2025, 3),
+    Puzzle::new::<year2025::day04::Day04>(2025, 4),
+    Puzzle::new::<year2025::day05::Day05>(2025, 5),
+    Puzzle::new::<year2025::day06::Day06>(2025, 6),
+    Puzzle::new::<year2025::day07::Day07>(2025, 7),
+    Puzzle::new::<year2025::day08::Day08>(2025, 8),
+    Puzzle::new::<year2025::day09::Day09>(2025, 9),
+    Puzzle::new::<year2025::day10::Day10>(2025, 10),
+    Puzzle::new::<year2025::day11::Day11>(2025, 11),
+];
+
+pub fn get(year: u32, day: u32) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|p| p.year == year && p.day == day)
+}
+
+pub fn year(year: u32) -> impl Iterator<Item = &'static Puzzle> {
+    PUZZLES.iter().filter(move |p| p.year == year)
+}
+
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = PUZZLES.iter().map(|p| p.year).collect();
+    years.dedup();
+    years
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sorted_and_unique() {
+        assert!(
+            PUZZLES
+                .windows(2)
+                .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day))
+        );
+    }
+
+    #[test]
+    fn test_lookup_and_invoke() {
+        let puzzle = get(2025, 1).unwrap();
+        let parsed = puzzle
+            .parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82")
+            .unwrap();
+        assert_eq!("3", parsed.part_1());
+        assert_eq!("6", parsed.part_2());
+    }
+
+    #[test]
+    fn test_parse_error_names_puzzle() {
+        let err = get(2025, 1).unwrap().parse("L68\nX30").err().unwrap();
+        assert_eq!(Some((2025, 1)), err.puzzle);
+        assert_eq!((2, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_unimplemented_parts() {
+        assert_eq!([false, false], get(2025, 8).unwrap().implemented);
+        assert_eq!([true, true], get(2023, 10).unwrap().implemented);
+        assert!(get(2025, 26).is_none());
+    }
+}
@@ -0,0 +1,141 @@
+use std::{fmt::Write, time::Duration};
+
+/// Outcome of running one part of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Unimplemented,
+    /// The answer differs from the one in the answers file.
+    Mismatch,
+    /// The input could not be parsed, so the part never ran.
+    InvalidInput,
+    /// The input file could not be read, so the part never ran.
+    MissingInput,
+    Panicked,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Unimplemented => "unimplemented",
+            Status::Mismatch => "mismatch",
+            Status::InvalidInput => "invalid_input",
+            Status::MissingInput => "missing_input",
+            Status::Panicked => "panicked",
+        }
+    }
+}
+
+/// One line of a machine-readable report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub elapsed: Option<Duration>,
+    /// Answer from the answers file, when checking against one.
+    pub expected: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Renders `rows` as a JSON array with one object per row.
+pub fn to_json(rows: &[Row]) -> String {
+    let mut json = String::from("[");
+    for (i, row) in rows.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        let _ = write!(
+            json,
+            "\n  {{\"year\": {}, \"day\": {}, \"part\": {}, \"status\": \"{}\", \"answer\": {}, \"nanos\": {}",
+            row.year,
+            row.day,
+            row.part,
+            row.status.as_str(),
+            optional_string(row.answer.as_deref()),
+            row.elapsed
+                .map_or_else(|| "null".to_string(), |e| e.as_nanos().to_string()),
+        );
+        if let Some(expected) = &row.expected {
+            let _ = write!(json, ", \"expected\": {}", string(expected));
+        }
+        if let Some(error) = &row.error {
+            let _ = write!(json, ", \"error\": {}", string(error));
+        }
+        json.push('}');
+    }
+    if !rows.is_empty() {
+        json.push('\n');
+    }
+    json.push(']');
+    json
+}
+
+fn optional_string(s: Option<&str>) -> String {
+    s.map_or_else(|| "null".to_string(), string)
+}
+
+/// Quoted and escaped JSON string.
+fn string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let rows = [
+            Row {
+                year: 2025,
+                day: 1,
+                part: 1,
+                status: Status::Mismatch,
+                answer: Some("42".to_string()),
+                elapsed: Some(Duration::from_micros(3)),
+                expected: Some("43".to_string()),
+                error: None,
+            },
+            Row {
+                year: 2025,
+                day: 8,
+                part: 2,
+                status: Status::Unimplemented,
+                answer: None,
+                elapsed: None,
+                expected: None,
+                error: None,
+            },
+        ];
+        assert_eq!(
+            "[\n  {\"year\": 2025, \"day\": 1, \"part\": 1, \"status\": \"mismatch\", \"answer\": \"42\", \"nanos\": 3000, \"expected\": \"43\"},\n  {\"year\": 2025, \"day\": 8, \"part\": 2, \"status\": \"unimplemented\", \"answer\": null, \"nanos\": null}\n]",
+            to_json(&rows)
+        );
+        assert_eq!("[]", to_json(&[]));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(r#""a\"b\\c\nd\u0001""#, string("a\"b\\c\nd\u{1}"));
+    }
+}
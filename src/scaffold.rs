@@ -0,0 +1,276 @@
+//! Generates and registers the module of a new day, see the `new` subcommand.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{inputs, registry};
+
+pub fn module_name(day: u32) -> String {
+    format!("day{day:02}")
+}
+
+/// Stub module of a day, the parts stay `todo!()` and their tests ignored until solved.
+pub fn module_source(day: u32) -> String {
+    let name = module_name(day);
+    let solution = format!("D{}", &name[1..]);
+    format!(
+        r#"use std::fmt::Display;
+
+use crate::{{error::ParseError, solution::Solution}};
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {{
+    Ok(input.lines().collect())
+}}
+
+pub fn part_1(_lines: &[&str]) -> u64 {{
+    todo!()
+}}
+
+pub fn part_2(_lines: &[&str]) -> u64 {{
+    todo!()
+}}
+
+pub struct {solution};
+
+impl Solution for {solution} {{
+    type Input<'a> = Vec<&'a str>;
+
+    const IMPLEMENTED: [bool; 2] = [false, false];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {{
+        parse(input)
+    }}
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {{
+        part_1(input)
+    }}
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {{
+        part_2(input)
+    }}
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "";
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_part_1() {{
+        assert_eq!(0, part_1(&parse(SAMPLE_INPUT).unwrap()));
+    }}
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_part_2() {{
+        assert_eq!(0, part_2(&parse(SAMPLE_INPUT).unwrap()));
+    }}
+}}
+"#
+    )
+}
+
+/// Inserts `line` among the lines that `key` recognizes, keeping them sorted.
+/// Without any such line it is appended.
+fn insert_sorted<K: Ord>(source: &str, line: &str, key: impl Fn(&str) -> Option<K>) -> String {
+    let new_key = key(line.trim()).expect("inserted line has a key");
+    let lines: Vec<&str> = source.lines().collect();
+
+    let group: Vec<usize> = (0..lines.len())
+        .filter(|&i| key(lines[i].trim()).is_some())
+        .collect();
+    let at = match group
+        .iter()
+        .find(|&&i| key(lines[i].trim()).is_some_and(|k| k > new_key))
+    {
+        Some(&i) => i,
+        None => group.last().map_or(lines.len(), |&last| last + 1),
+    };
+
+    let mut lines = lines;
+    lines.insert(at, line);
+    lines.join("\n") + "\n"
+}
+
+fn module_key(prefix: &'static str) -> impl Fn(&str) -> Option<String> {
+    move |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|name| name.strip_suffix(';'))
+            .filter(|name| name.starts_with(prefix))
+            .map(str::to_string)
+    }
+}
+
+/// Adds `pub mod dayNN;` to a year's `mod.rs`, sorted like rustfmt does.
+pub fn register_module(mod_rs: &str, day: u32) -> String {
+    let line = format!("pub mod {};", module_name(day));
+    insert_sorted(mod_rs, &line, module_key("day"))
+}
+
+/// Adds `pub mod yearYYYY;` to `lib.rs`.
+pub fn register_year(lib_rs: &str, year: u32) -> String {
+    let line = format!("pub mod year{year};");
+    insert_sorted(lib_rs, &line, module_key("year"))
+}
+
+/// Adds the day to `registry::PUZZLES`, importing its year module if needed.
+pub fn register_puzzle(registry_rs: &str, year: u32, day: u32) -> String {
+    let name = module_name(day);
+    let line = format!(
+        "    Puzzle::new::<year{year}::{name}::D{}>({year}, {day}),",
+        &name[1..]
+    );
+    let puzzle_key = |line: &str| {
+        let args = line.strip_prefix("Puzzle::new::<")?.rsplit_once('(')?.1;
+        let (year, day) = args.strip_suffix("),")?.split_once(", ")?;
+        Some((year.parse::<u32>().ok()?, day.parse::<u32>().ok()?))
+    };
+    let registry_rs = insert_sorted(registry_rs, &line, puzzle_key);
+
+    let year_module = format!("year{year}");
+    let mut out = String::with_capacity(registry_rs.len() + year_module.len() + 2);
+    for line in registry_rs.lines() {
+        match line
+            .strip_prefix("use crate::{")
+            .and_then(|items| items.strip_suffix("};"))
+        {
+            Some(items) if !items.split(", ").any(|item| item == year_module) => {
+                let mut items: Vec<&str> = items.split(", ").collect();
+                items.push(&year_module);
+                items.sort_unstable();
+                out.push_str(&format!("use crate::{{{}}};", items.join(", ")));
+            }
+            _ => out.push_str(line),
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Creates the module, registers it and creates an empty input file below the crate `root`.
+/// Returns the created or modified files, fails if the day already exists.
+pub fn scaffold(root: &Path, year: u32, day: u32) -> io::Result<Vec<PathBuf>> {
+    let year_dir = root.join(format!("src/year{year}"));
+    let already_exists = |what: String| {
+        Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{what} already exists"),
+        ))
+    };
+    if registry::get(year, day).is_some() {
+        return already_exists(format!("{year} day {day}"));
+    }
+    // Older years name their modules without padding, like `day3.rs`
+    for name in [format!("day{day}"), module_name(day)] {
+        let path = year_dir.join(format!("{name}.rs"));
+        if path.exists() {
+            return already_exists(path.display().to_string());
+        }
+    }
+    let module = year_dir.join(format!("{}.rs", module_name(day)));
+
+    let mut touched = Vec::new();
+    let mut update = |path: PathBuf, f: &dyn Fn(&str) -> String| -> io::Result<()> {
+        let source = fs::read_to_string(&path).or_else(|err| match err.kind() {
+            io::ErrorKind::NotFound => Ok(String::new()),
+            _ => Err(err),
+        })?;
+        fs::write(&path, f(&source))?;
+        touched.push(path);
+        Ok(())
+    };
+
+    // A new year starts with an empty `mod.rs` registered in `lib.rs`
+    if !year_dir.exists() {
+        fs::create_dir_all(&year_dir)?;
+        update(root.join("src/lib.rs"), &|lib_rs| {
+            register_year(lib_rs, year)
+        })?;
+    }
+    update(year_dir.join("mod.rs"), &|mod_rs| {
+        register_module(mod_rs, day)
+    })?;
+    update(module.clone(), &|_| module_source(day))?;
+    update(root.join("src/registry.rs"), &|registry_rs| {
+        register_puzzle(registry_rs, year, day)
+    })?;
+
+    let input = inputs::cache_path(&root.join(inputs::INPUTS_DIR), year, day);
+    if !input.exists() {
+        fs::create_dir_all(input.parent().expect("cache path has a parent"))?;
+        fs::write(&input, "")?;
+        touched.push(input);
+    }
+
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_register_module() {
+        let mod_rs = "pub mod day1;\npub mod day10;\npub mod day2;\n";
+        assert_eq!(
+            "pub mod day1;\npub mod day10;\npub mod day11;\npub mod day2;\n",
+            register_module(mod_rs, 11)
+        );
+        assert_eq!("pub mod day01;\n", register_module("", 1));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib_rs = "pub mod util;\npub mod year2023;\npub mod year2025;\n";
+        assert_eq!(
+            "pub mod util;\npub mod year2023;\npub mod year2024;\npub mod year2025;\n",
+            register_year(lib_rs, 2024)
+        );
+    }
+
+    #[test]
+    fn test_register_puzzle() {
+        let registry_rs = "use crate::{solution::Solution, year2023, year2025};
+
+pub static PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<year2023::day10::Day10>(2023, 10),
+    Puzzle::new::<year2025::day01::Day01>(2025, 1),
+];
+";
+        assert_eq!(
+            "use crate::{solution::Solution, year2023, year2024, year2025};
+
+pub static PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<year2023::day10::Day10>(2023, 10),
+    Puzzle::new::<year2024::day03::Day03>(2024, 3),
+    Puzzle::new::<year2025::day01::Day01>(2025, 1),
+];
+",
+            register_puzzle(registry_rs, 2024, 3)
+        );
+    }
+
+    #[test]
+    fn test_scaffold_existing_day() {
+        let root = env::temp_dir().join(format!("aoc-rs-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/year2019")).unwrap();
+        fs::write(root.join("src/year2019/day3.rs"), "").unwrap();
+
+        let err = scaffold(&root, 2019, 3).unwrap_err();
+        assert_eq!(io::ErrorKind::AlreadyExists, err.kind());
+        assert!(!root.join("src/year2019/day03.rs").exists());
+        assert!(!root.join("src/year2019/mod.rs").exists());
+
+        let err = scaffold(&root, 2023, 3).unwrap_err();
+        assert_eq!(io::ErrorKind::AlreadyExists, err.kind());
+        assert!(!root.join("src/year2023").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+use crate::error::ParseError;
+
+/// Common shape of every day: `parse` runs once and both parts share its output.
+///
+/// `Input` is generic over the lifetime of the raw input so days can keep borrowing
+/// slices of it instead of allocating owned strings.
+pub trait Solution {
+    type Input<'a>;
+
+    /// Parts that are still a `todo!()` override this so runners skip them instead of panicking.
+    const IMPLEMENTED: [bool; 2] = [true, true];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display;
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display;
+}
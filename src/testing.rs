@@ -0,0 +1,42 @@
+//! Randomized cross-checks of optimized solutions against brute-force references.
+
+use std::fmt::Debug;
+
+use crate::util::rng::Rng;
+
+/// Number of random inputs tried by [`cross_check`] unless `AOC_CROSS_CHECK_CASES` is set.
+pub const CASES: u64 = 500;
+
+/// Runs `fast` and `naive` on inputs drawn by `generate` and panics on the first disagreement.
+///
+/// Every case uses its own seed, which is reported together with the input so a failure can
+/// be replayed with `generate(&mut Rng::new(seed))`.
+pub fn cross_check<I: Debug, O: PartialEq + Debug>(
+    mut generate: impl FnMut(&mut Rng) -> I,
+    fast: impl Fn(&I) -> O,
+    naive: impl Fn(&I) -> O,
+) {
+    let cases = std::env::var("AOC_CROSS_CHECK_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(CASES);
+
+    for seed in 0..cases {
+        let input = generate(&mut Rng::new(seed));
+        let (expected, actual) = (naive(&input), fast(&input));
+        assert_eq!(
+            expected, actual,
+            "seed {seed} disagrees with the reference on {input:?}"
+        );
+    }
+}
+
+mod test {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "seed 0 disagrees")]
+    fn test_cross_check_reports_seed() {
+        cross_check(|rng| rng.below(10), |&n| n, |&n| n + 1);
+    }
+}
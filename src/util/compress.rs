@@ -0,0 +1,170 @@
+//! Coordinate compression: every distinct value gets a cell of width 1, and every gap between
+//! two of them a single cell standing for the whole gap.
+
+use crate::util::point::Point;
+
+/// One compressed axis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Axis {
+    /// Cell `i` covers the values `bounds[i]..bounds[i + 1]`.
+    bounds: Vec<i64>,
+}
+
+impl Axis {
+    pub fn new(values: impl IntoIterator<Item = i32>) -> Self {
+        let mut values: Vec<i64> = values.into_iter().map(i64::from).collect();
+        values.sort_unstable();
+        values.dedup();
+
+        let mut bounds = Vec::with_capacity(2 * values.len());
+        for (i, &v) in values.iter().enumerate() {
+            bounds.push(v);
+            // Opens the gap to the next value, or closes the last cell
+            if values.get(i + 1).is_none_or(|&next| next > v + 1) {
+                bounds.push(v + 1);
+            }
+        }
+        Axis { bounds }
+    }
+
+    /// Like [`Axis::new`], with one more cell below the smallest and above the largest value so
+    /// the outside of the values is always represented.
+    pub fn with_border(values: impl IntoIterator<Item = i32>) -> Self {
+        let values: Vec<i32> = values.into_iter().collect();
+        let border = match (values.iter().min(), values.iter().max()) {
+            (Some(&min), Some(&max)) => vec![min.saturating_sub(1), max.saturating_add(1)],
+            _ => Vec::new(),
+        };
+        Axis::new(values.into_iter().chain(border))
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.bounds.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Cell holding `value`, `None` outside of the axis.
+    pub fn compress(&self, value: i32) -> Option<i32> {
+        let value = i64::from(value);
+        let (&first, &last) = (self.bounds.first()?, self.bounds.last()?);
+        if !(first..last).contains(&value) {
+            return None;
+        }
+        Some(self.bounds.partition_point(|&b| b <= value) as i32 - 1)
+    }
+
+    /// Smallest value covered by `cell`.
+    pub fn decompress(&self, cell: i32) -> i32 {
+        self.bounds[cell as usize] as i32
+    }
+
+    /// Number of original values covered by `cell`.
+    pub fn width(&self, cell: i32) -> u64 {
+        (self.bounds[cell as usize + 1] - self.bounds[cell as usize]) as u64
+    }
+}
+
+/// Both axes of a set of points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compression {
+    pub x: Axis,
+    pub y: Axis,
+}
+
+impl Compression {
+    pub fn new(points: impl IntoIterator<Item = Point>) -> Self {
+        let (xs, ys): (Vec<i32>, Vec<i32>) = points.into_iter().map(|p| (p.x, p.y)).unzip();
+        Compression {
+            x: Axis::new(xs),
+            y: Axis::new(ys),
+        }
+    }
+
+    /// Like [`Compression::new`], with a border of cells all around the points.
+    pub fn with_border(points: impl IntoIterator<Item = Point>) -> Self {
+        let (xs, ys): (Vec<i32>, Vec<i32>) = points.into_iter().map(|p| (p.x, p.y)).unzip();
+        Compression {
+            x: Axis::with_border(xs),
+            y: Axis::with_border(ys),
+        }
+    }
+
+    /// Width and height of the compressed grid.
+    pub fn size(&self) -> (i32, i32) {
+        (self.x.len() as i32, self.y.len() as i32)
+    }
+
+    pub fn compress(&self, point: Point) -> Option<Point> {
+        Some(Point::new(
+            self.x.compress(point.x)?,
+            self.y.compress(point.y)?,
+        ))
+    }
+
+    /// Top-left original point of `cell`.
+    pub fn decompress(&self, cell: Point) -> Point {
+        Point::new(self.x.decompress(cell.x), self.y.decompress(cell.y))
+    }
+
+    /// Number of original points covered by `cell`.
+    pub fn area(&self, cell: Point) -> u64 {
+        self.x.width(cell.x) * self.y.width(cell.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_axis() {
+        // Cells: [2] [3] [4..7) [7]
+        let axis = Axis::new([7, 2, 3, 7]);
+        assert_eq!(4, axis.len());
+        assert_eq!(
+            vec![Some(0), Some(1), Some(2), Some(2), Some(3)],
+            [2, 3, 4, 6, 7].map(|v| axis.compress(v)).to_vec()
+        );
+        assert_eq!((None, None), (axis.compress(1), axis.compress(8)));
+        assert_eq!(4, axis.decompress(2));
+        assert_eq!(
+            vec![1, 1, 3, 1],
+            (0..4).map(|c| axis.width(c)).collect::<Vec<_>>()
+        );
+        assert!(Axis::new([]).is_empty());
+    }
+
+    #[test]
+    fn test_with_border() {
+        let axis = Axis::with_border([0, i32::MAX - 1]);
+        assert_eq!(Some(0), axis.compress(-1));
+        assert_eq!(Some(4), axis.compress(i32::MAX));
+        assert_eq!(
+            (1 << 31) + 1,
+            (0..axis.len() as i32).map(|c| axis.width(c)).sum::<u64>()
+        );
+    }
+
+    #[test]
+    fn test_area_is_preserved() {
+        let points = [Point::new(0, 0), Point::new(10, 3), Point::new(4, -5)];
+        let compression = Compression::new(points);
+        let (width, height) = compression.size();
+
+        let total: u64 = (0..width)
+            .flat_map(|x| (0..height).map(move |y| Point::new(x, y)))
+            .map(|cell| compression.area(cell))
+            .sum();
+        assert_eq!(11 * 9, total);
+
+        for p in points {
+            let cell = compression.compress(p).unwrap();
+            assert_eq!(p, compression.decompress(cell));
+            assert_eq!(1, compression.area(cell));
+        }
+    }
+}
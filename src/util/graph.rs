@@ -0,0 +1,217 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap as HashMap;
+
+/// Directed acyclic graph over nodes of any key type, interned to dense indices in `0..len()`.
+#[derive(Debug, Clone)]
+pub struct Dag<K> {
+    nodes: Vec<K>,
+    index: HashMap<K, usize>,
+    edges: Vec<Vec<usize>>,
+    /// Node indices in topological order, and the position of every node in it.
+    order: Vec<usize>,
+    position: Vec<usize>,
+}
+
+/// A node on a cycle, returned instead of a [`Dag`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle<K>(pub K);
+
+impl<K: Copy + Eq + Hash> Dag<K> {
+    pub fn new(edges: impl IntoIterator<Item = (K, K)>) -> Result<Self, Cycle<K>> {
+        let mut dag = Dag {
+            nodes: Vec::new(),
+            index: HashMap::default(),
+            edges: Vec::new(),
+            order: Vec::new(),
+            position: Vec::new(),
+        };
+        for (from, to) in edges {
+            let (from, to) = (dag.intern(from), dag.intern(to));
+            dag.edges[from].push(to);
+        }
+        dag.sort()?;
+        Ok(dag)
+    }
+
+    fn intern(&mut self, node: K) -> usize {
+        *self.index.entry(node).or_insert_with(|| {
+            self.nodes.push(node);
+            self.edges.push(Vec::new());
+            self.nodes.len() - 1
+        })
+    }
+
+    /// Kahn's algorithm, a node left with incoming edges lies on or behind a cycle.
+    fn sort(&mut self) -> Result<(), Cycle<K>> {
+        let mut incoming = vec![0usize; self.nodes.len()];
+        self.edges
+            .iter()
+            .flatten()
+            .for_each(|&to| incoming[to] += 1);
+
+        let mut order: Vec<usize> = (0..self.nodes.len())
+            .filter(|&n| incoming[n] == 0)
+            .collect();
+        let mut next = 0;
+        while let Some(&from) = order.get(next) {
+            next += 1;
+            for &to in &self.edges[from] {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    order.push(to);
+                }
+            }
+        }
+
+        if order.len() < self.nodes.len() {
+            // Walking back along unsorted nodes must eventually come around
+            let mut seen = vec![false; self.nodes.len()];
+            let mut node = incoming.iter().position(|&n| n > 0).unwrap();
+            while !seen[node] {
+                seen[node] = true;
+                node = (0..self.nodes.len())
+                    .find(|&from| incoming[from] > 0 && self.edges[from].contains(&node))
+                    .unwrap();
+            }
+            return Err(Cycle(self.nodes[node]));
+        }
+
+        self.position = vec![0; order.len()];
+        order
+            .iter()
+            .enumerate()
+            .for_each(|(pos, &n)| self.position[n] = pos);
+        self.order = order;
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn index(&self, node: K) -> Option<usize> {
+        self.index.get(&node).copied()
+    }
+
+    pub fn node(&self, index: usize) -> K {
+        self.nodes[index]
+    }
+
+    pub fn topological_order(&self) -> impl Iterator<Item = K> {
+        self.order.iter().map(|&n| self.nodes[n])
+    }
+
+    /// Number of paths from `from` to `to`, zero if either is not in the graph.
+    pub fn count_paths(&self, from: K, to: K) -> u64 {
+        match (self.index(from), self.index(to)) {
+            (Some(from), Some(to)) => self.count_between(from, to),
+            _ => 0,
+        }
+    }
+
+    fn count_between(&self, from: usize, to: usize) -> u64 {
+        let (start, end) = (self.position[from], self.position[to]);
+        if start > end {
+            return 0;
+        }
+
+        let mut paths = vec![0u64; self.nodes.len()];
+        paths[from] = 1;
+        for &n in &self.order[start..end] {
+            if paths[n] == 0 {
+                continue;
+            }
+            for &next in &self.edges[n] {
+                paths[next] += paths[n];
+            }
+        }
+        paths[to]
+    }
+
+    /// Number of paths from `from` to `to` that visit every waypoint, in any order.
+    ///
+    /// Along any path the waypoints appear in topological order, so only that order is counted.
+    pub fn count_paths_through(&self, from: K, to: K, waypoints: &[K]) -> u64 {
+        let Some(mut stops) = [from, to]
+            .iter()
+            .chain(waypoints)
+            .map(|&node| self.index(node))
+            .collect::<Option<Vec<usize>>>()
+        else {
+            return 0;
+        };
+        let (from, to) = (stops[0], stops[1]);
+        stops.drain(..2);
+        stops.sort_unstable_by_key(|&n| self.position[n]);
+        stops.dedup();
+
+        let mut total = 1;
+        let mut prev = from;
+        for stop in stops.into_iter().chain([to]) {
+            total *= self.count_between(prev, stop);
+            if total == 0 {
+                break;
+            }
+            prev = stop;
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // a -> b -> d, a -> c -> d, b -> c, d -> e
+    const EDGES: [(&str, &str); 6] = [
+        ("a", "b"),
+        ("a", "c"),
+        ("b", "c"),
+        ("b", "d"),
+        ("c", "d"),
+        ("d", "e"),
+    ];
+
+    #[test]
+    fn test_topological_order() {
+        let dag = Dag::new(EDGES).unwrap();
+        assert_eq!(5, dag.len());
+        let order: Vec<&str> = dag.topological_order().collect();
+        for (from, to) in EDGES {
+            let pos = |node| order.iter().position(|&n| n == node).unwrap();
+            assert!(pos(from) < pos(to), "{from} before {to} in {order:?}");
+        }
+        assert_eq!("c", dag.node(dag.index("c").unwrap()));
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let dag = Dag::new(EDGES).unwrap();
+        assert_eq!(3, dag.count_paths("a", "e"));
+        assert_eq!(2, dag.count_paths("b", "e"));
+        assert_eq!(1, dag.count_paths("e", "e"));
+        assert_eq!(0, dag.count_paths("e", "a"));
+        assert_eq!(0, dag.count_paths("a", "z"));
+    }
+
+    #[test]
+    fn test_count_paths_through() {
+        let dag = Dag::new(EDGES).unwrap();
+        assert_eq!(3, dag.count_paths_through("a", "e", &[]));
+        assert_eq!(1, dag.count_paths_through("a", "e", &["c", "b"]));
+        assert_eq!(2, dag.count_paths_through("a", "e", &["c"]));
+        assert_eq!(0, dag.count_paths_through("b", "e", &["a"]));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let edges = [(1, 2), (2, 3), (3, 4), (4, 2), (0, 1)];
+        let Cycle(node) = Dag::new(edges).unwrap_err();
+        assert!([2, 3, 4].contains(&node));
+    }
+}
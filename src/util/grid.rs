@@ -3,7 +3,10 @@ use std::{
     ops::{Index, IndexMut},
 };
 
-use crate::util::point::Point;
+use crate::{
+    error::ParseError,
+    util::point::{DIRECTIONS, Point},
+};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Grid<T> {
@@ -33,31 +36,103 @@ impl<T: fmt::Debug> fmt::Debug for Grid<T> {
 // Usage: println!("{}", grid);
 impl fmt::Display for Grid<u8> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "Map View ({}x{}):", self.width, self.height)?;
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let val = self.data[(self.width * y + x) as usize];
-                write!(f, "{}", val as char)?;
-            }
-            writeln!(f)?;
+        render_map(f, self.width, self.height, |x, y| {
+            self.data[(self.width * y + x) as usize]
+        })
+    }
+}
+
+/// The `Display` of byte maps, `cell` is called with coordinates relative to the top-left corner.
+pub(super) fn render_map(
+    f: &mut fmt::Formatter<'_>,
+    width: i32,
+    height: i32,
+    cell: impl Fn(i32, i32) -> u8,
+) -> fmt::Result {
+    writeln!(f, "Map View ({}x{}):", width, height)?;
+    for y in 0..height {
+        for x in 0..width {
+            write!(f, "{}", cell(x, y) as char)?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+/// How [`Grid::parse_with`] turns lines into rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Pads rows shorter than the longest one with this byte, instead of rejecting them.
+    pub fill: Option<u8>,
+    /// Removes the `\r` of CRLF line endings, so it does not become a column.
+    pub strip_cr: bool,
+    /// Ignores empty lines at the end of the input.
+    pub trim_trailing: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            fill: None,
+            strip_cr: true,
+            trim_trailing: false,
         }
-        Ok(())
     }
 }
 
 impl Grid<u8> {
-    pub fn parse(input: &str) -> Self {
-        let raw: Vec<_> = input.lines().map(str::as_bytes).collect();
+    /// Parses a grid whose rows all have the width of the first one.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, ParseOptions::default())
+    }
 
-        let width = raw[0].len() as i32;
-        let height = raw.len() as i32;
-        let bytes = raw.concat();
+    pub fn parse_with(input: &str, options: ParseOptions) -> Result<Self, ParseError> {
+        let mut rows: Vec<&str> = input
+            .split_terminator('\n')
+            .map(|row| {
+                if options.strip_cr {
+                    row.strip_suffix('\r').unwrap_or(row)
+                } else {
+                    row
+                }
+            })
+            .collect();
+        if options.trim_trailing {
+            while rows.last().is_some_and(|row| row.is_empty()) {
+                rows.pop();
+            }
+        }
 
-        Grid {
-            width,
-            height,
-            data: bytes,
+        let Some(first) = rows.first() else {
+            return Err(ParseError::end_of_input(input, "grid row"));
+        };
+        let width = match options.fill {
+            Some(_) => rows.iter().map(|row| row.len()).max().unwrap_or(0),
+            None => first.len(),
+        };
+        if width == 0 {
+            return Err(ParseError::at(input, first, "grid row"));
+        }
+
+        let mut data = Vec::with_capacity(width * rows.len());
+        for row in &rows {
+            if row.len() != width && options.fill.is_none() {
+                // Point at the first missing or extra cell
+                let at = row.get(row.len().min(width)..).unwrap_or(row);
+                return Err(ParseError::at(input, at, format!("row of width {width}")));
+            }
+            data.extend_from_slice(row.as_bytes());
+            data.resize(
+                data.len() + width - row.len(),
+                options.fill.unwrap_or_default(),
+            );
         }
+
+        Ok(Grid {
+            width: width as i32,
+            height: rows.len() as i32,
+            data,
+        })
     }
 
     pub fn copy_maze_with(&self, symbol: u8) -> Self {
@@ -95,20 +170,93 @@ impl<T: Copy + PartialEq + Debug> Grid<T> {
 
     // #[inline(never)]
     pub fn get(&self, point: Point) -> Option<&T> {
-        if point.x >= self.width || point.y >= self.height || point.x < 0 || point.y < 0 {
+        if !self.contains(point) {
             return None;
         }
         Some(&self[point])
     }
 
     pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
-        if point.x >= self.width || point.y >= self.height || point.x < 0 || point.y < 0 {
+        if !self.contains(point) {
             return None;
         }
         Some(&mut self[point])
     }
 }
 
+impl<T> Grid<T> {
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width).contains(&point.x) && (0..self.height).contains(&point.y)
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(idx, e)| (Point::new(idx as i32 % width, idx as i32 / width), e))
+    }
+
+    /// In-bounds neighbors among `directions`. Only the size of the grid is captured, so the grid
+    /// can be mutated while iterating.
+    fn neighbors(
+        &self,
+        point: Point,
+        directions: &'static [Point],
+    ) -> impl Iterator<Item = Point> + use<T> {
+        let (width, height) = (self.width, self.height);
+        directions
+            .iter()
+            .map(move |&d| point + d)
+            .filter(move |p| (0..width).contains(&p.x) && (0..height).contains(&p.y))
+    }
+
+    /// In-bounds orthogonal neighbors of `point`.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + use<T> {
+        self.neighbors(point, &DIRECTIONS[..4])
+    }
+
+    /// In-bounds orthogonal and diagonal neighbors of `point`.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + use<T> {
+        self.neighbors(point, &DIRECTIONS)
+    }
+
+    pub fn neighbor_cells4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbors4(point).map(|p| (p, &self[p]))
+    }
+
+    pub fn neighbor_cells8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbors8(point).map(|p| (p, &self[p]))
+    }
+
+    /// Maps `point` back into the grid as if its edges were glued together.
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(
+            point.x.rem_euclid(self.width),
+            point.y.rem_euclid(self.height),
+        )
+    }
+
+    /// Orthogonal neighbors on the torus, always 4 of them.
+    pub fn neighbors4_wrapping(&self, point: Point) -> impl Iterator<Item = Point> + use<T> {
+        let (width, height) = (self.width, self.height);
+        DIRECTIONS[..4].iter().map(move |&d| {
+            let p = point + d;
+            Point::new(p.x.rem_euclid(width), p.y.rem_euclid(height))
+        })
+    }
+
+    /// Orthogonal and diagonal neighbors on the torus, always 8 of them.
+    pub fn neighbors8_wrapping(&self, point: Point) -> impl Iterator<Item = Point> + use<T> {
+        let (width, height) = (self.width, self.height);
+        DIRECTIONS.iter().map(move |&d| {
+            let p = point + d;
+            Point::new(p.x.rem_euclid(width), p.y.rem_euclid(height))
+        })
+    }
+}
+
 impl<T> Index<(i32, i32)> for Grid<T> {
     type Output = T;
 
@@ -137,7 +285,7 @@ impl<T> IndexMut<Point> for Grid<T> {
     }
 }
 
-impl<T: Copy> Grid<T> {
+impl<T: Clone> Grid<T> {
     pub fn new(width: i32, height: i32, value: T) -> Grid<T> {
         Grid {
             width,
@@ -146,3 +294,79 @@ impl<T: Copy> Grid<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.\n.#\n").unwrap();
+        assert_eq!((2, 2), (grid.width, grid.height));
+        assert_eq!(b"#..#", grid.data.as_slice());
+        assert_eq!(grid, Grid::parse("#.\r\n.#\r\n").unwrap());
+        assert_eq!(grid, Grid::parse("#.\n.#").unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse("#..\n.#\n").unwrap_err();
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!("row of width 3", err.expected);
+
+        let err = Grid::parse("#.\n.#.\n").unwrap_err();
+        assert_eq!((2, 3), (err.line, err.column));
+
+        let err = Grid::parse("#.\n.#\n\n").unwrap_err();
+        assert_eq!((3, 1), (err.line, err.column));
+
+        assert!(Grid::parse("").is_err());
+        assert!(Grid::parse("\n#").is_err());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse("abc\ndef\nghi\n").unwrap();
+        let sorted = |points: Vec<Point>| {
+            let mut cells: Vec<u8> = points.into_iter().map(|p| grid[p]).collect();
+            cells.sort_unstable();
+            String::from_utf8(cells).unwrap()
+        };
+
+        let corner = Point::new(0, 0);
+        let center = Point::new(1, 1);
+        assert_eq!("bd", sorted(grid.neighbors4(corner).collect()));
+        assert_eq!("bde", sorted(grid.neighbors8(corner).collect()));
+        assert_eq!("bdfh", sorted(grid.neighbors4(center).collect()));
+        assert_eq!("abcdfghi", sorted(grid.neighbors8(center).collect()));
+
+        assert_eq!("bcdg", sorted(grid.neighbors4_wrapping(corner).collect()));
+        assert_eq!(
+            "bcdefghi",
+            sorted(grid.neighbors8_wrapping(corner).collect())
+        );
+        assert_eq!(Point::new(2, 0), grid.wrap(Point::new(-1, 3)));
+
+        let cells: Vec<(Point, &u8)> = grid.neighbor_cells4(corner).collect();
+        assert!(cells.iter().all(|&(p, &e)| grid[p] == e));
+        assert_eq!((Point::new(2, 1), &b'f'), grid.cells().nth(5).unwrap());
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let options = ParseOptions {
+            fill: Some(b' '),
+            trim_trailing: true,
+            ..ParseOptions::default()
+        };
+        let grid = Grid::parse_with("#\n.##\n\n\n", options).unwrap();
+        assert_eq!((3, 2), (grid.width, grid.height));
+        assert_eq!(b"#  .##", grid.data.as_slice());
+
+        let raw = ParseOptions {
+            strip_cr: false,
+            ..ParseOptions::default()
+        };
+        assert_eq!(3, Grid::parse_with("#.\r\n", raw).unwrap().width);
+    }
+}
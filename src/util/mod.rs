@@ -1,3 +1,11 @@
+pub mod compress;
+pub mod graph;
 pub mod grid;
 pub mod math;
+pub mod parse;
 pub mod point;
+pub mod polygon;
+pub mod prefix_sum;
+pub mod rng;
+pub mod search;
+pub mod sparse_grid;
@@ -0,0 +1,276 @@
+//! Zero-allocation helpers shared by the day parsers.
+//!
+//! Every helper takes the whole puzzle `input` next to the slice it works on, so errors
+//! point at the right line and column like [`parse_at`](crate::error::parse_at) does.
+
+use std::{any::type_name, iter::FusedIterator, marker::PhantomData, str::FromStr};
+
+use crate::error::ParseError;
+
+/// Integer types that can be extracted with [`integers`].
+pub trait Integer: FromStr + Copy + Default {
+    const SIGNED: bool;
+}
+
+macro_rules! integer {
+    ($signed:literal: $($t:ty)*) => {
+        $(impl Integer for $t {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+integer!(false: u8 u16 u32 u64 u128 usize);
+integer!(true: i8 i16 i32 i64 i128 isize);
+
+/// Iterator over the integers of a string, see [`integers`].
+pub struct Integers<'a, T> {
+    input: &'a str,
+    s: &'a str,
+    pos: usize,
+    _marker: PhantomData<T>,
+}
+
+/// Every integer in `s`, skipping whatever text lies between them.
+///
+/// For signed types a `-` directly before the digits is a sign unless it follows another
+/// digit, so `3-5` is read as `3, 5` and `3 -5` as `3, -5`. Numbers that overflow `T` are
+/// reported as errors.
+pub fn integers<'a, T: Integer>(input: &'a str, s: &'a str) -> Integers<'a, T> {
+    Integers {
+        input,
+        s,
+        pos: 0,
+        _marker: PhantomData,
+    }
+}
+
+impl<T: Integer> Iterator for Integers<'_, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.s.as_bytes();
+        let digits = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+        let end = digits
+            + bytes[digits..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(bytes.len() - digits);
+
+        let negative = T::SIGNED
+            && digits > 0
+            && bytes[digits - 1] == b'-'
+            && (digits < 2 || !bytes[digits - 2].is_ascii_digit());
+        let start = if negative { digits - 1 } else { digits };
+
+        self.pos = end;
+        let token = &self.s[start..end];
+        Some(token.parse().map_err(|_| {
+            ParseError::at(
+                self.input,
+                token,
+                format!("integer fitting in {}", type_name::<T>()),
+            )
+        }))
+    }
+}
+
+impl<T: Integer> FusedIterator for Integers<'_, T> {}
+
+/// Exactly `N` integers from `s`, `expected` describes the whole slice when the count is off.
+pub fn integer_array<T: Integer, const N: usize>(
+    input: &str,
+    s: &str,
+    expected: &str,
+) -> Result<[T; N], ParseError> {
+    let mut numbers = integers(input, s);
+    let mut array = [T::default(); N];
+    for slot in &mut array {
+        *slot = numbers
+            .next()
+            .ok_or_else(|| ParseError::at(input, s, expected))??;
+    }
+    if numbers.next().is_some() {
+        return Err(ParseError::at(input, s, expected));
+    }
+    Ok(array)
+}
+
+/// Blocks of lines separated by blank lines, trailing newlines are ignored.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end_matches('\n').split("\n\n")
+}
+
+/// Exactly `N` blank-line separated sections of `input`.
+pub fn split_sections<const N: usize>(input: &str) -> Result<[&str; N], ParseError> {
+    let mut sections = sections(input);
+    let mut array = [""; N];
+    for slot in &mut array {
+        *slot = sections.next().ok_or_else(|| {
+            ParseError::end_of_input(input, format!("{N} blank line separated sections"))
+        })?;
+    }
+    if let Some(extra) = sections.next() {
+        return Err(ParseError::at(input, extra, format!("only {N} sections")));
+    }
+    Ok(array)
+}
+
+/// Splits `s` around its first `open ... close` block into the text before it, the contents
+/// of the block and the text after it. Blocks do not nest.
+pub fn delimited<'a>(
+    input: &str,
+    s: &'a str,
+    open: char,
+    close: char,
+) -> Result<(&'a str, &'a str, &'a str), ParseError> {
+    let start = s
+        .find(open)
+        .ok_or_else(|| ParseError::at(input, &s[s.len()..], format!("`{open}`")))?;
+    let inside = &s[start + open.len_utf8()..];
+    let end = inside
+        .find(close)
+        .ok_or_else(|| ParseError::at(input, &inside[inside.len()..], format!("`{close}`")))?;
+    Ok((
+        &s[..start],
+        &inside[..end],
+        &inside[end + close.len_utf8()..],
+    ))
+}
+
+/// Iterator over consecutive blocks such as `(1,3) (2)`, see [`blocks`].
+pub struct Blocks<'a> {
+    input: &'a str,
+    rest: &'a str,
+    open: char,
+    close: char,
+}
+
+/// Contents of every `open ... close` block of `s`, only whitespace may separate them.
+pub fn blocks<'a>(input: &'a str, s: &'a str, open: char, close: char) -> Blocks<'a> {
+    Blocks {
+        input,
+        rest: s,
+        open,
+        close,
+    }
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = Result<&'a str, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.trim_start();
+        if rest.is_empty() {
+            return None;
+        }
+
+        if !rest.starts_with(self.open) {
+            self.rest = "";
+            return Some(Err(ParseError::at(
+                self.input,
+                rest,
+                format!("`{}`", self.open),
+            )));
+        }
+
+        match delimited(self.input, rest, self.open, self.close) {
+            Ok((_, inside, after)) => {
+                self.rest = after;
+                Some(Ok(inside))
+            }
+            Err(err) => {
+                self.rest = "";
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl FusedIterator for Blocks<'_> {}
+
+/// Splits a `key<separator>value` line, trimming whitespace around both halves.
+pub fn key_value<'a>(
+    input: &str,
+    line: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    let (key, value) = line.split_once(separator).ok_or_else(|| {
+        ParseError::at(
+            input,
+            &line[line.len()..],
+            format!("`{}` after the key", separator.trim()),
+        )
+    })?;
+    Ok((key.trim(), value.trim()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        let input = "x=3-5, y=-7..+2";
+        let unsigned: Vec<u32> = integers(input, input).collect::<Result<_, _>>().unwrap();
+        assert_eq!(vec![3, 5, 7, 2], unsigned);
+        let signed: Vec<i32> = integers(input, input).collect::<Result<_, _>>().unwrap();
+        assert_eq!(vec![3, 5, -7, 2], signed);
+
+        let err = integers::<u8>("1 300", "1 300")
+            .nth(1)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!((1, 3), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_integer_array() {
+        assert_eq!(
+            Ok([1, 2, 3]),
+            integer_array::<u8, 3>("1,2,3", "1,2,3", "x,y,z")
+        );
+        assert!(integer_array::<u8, 3>("1,2", "1,2", "x,y,z").is_err());
+        assert!(integer_array::<u8, 3>("1,2,3,4", "1,2,3,4", "x,y,z").is_err());
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "a\nb\n\nc\n";
+        assert_eq!(vec!["a\nb", "c"], sections(input).collect::<Vec<_>>());
+        assert_eq!(Ok(["a\nb", "c"]), split_sections::<2>(input));
+        assert!(split_sections::<3>(input).is_err());
+        assert!(split_sections::<1>(input).is_err());
+    }
+
+    #[test]
+    fn test_delimited() {
+        let line = "[.##.] (3) (1,3) {3,5}";
+        let (before, lights, rest) = delimited(line, line, '[', ']').unwrap();
+        assert_eq!(("", ".##."), (before, lights));
+        let (buttons, joltage, after) = delimited(line, rest, '{', '}').unwrap();
+        assert_eq!(("3,5", ""), (joltage, after));
+
+        let wiring: Vec<_> = blocks(line, buttons, '(', ')')
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(vec!["3", "1,3"], wiring);
+
+        let err = blocks(line, &line[6..12], '(', ')')
+            .nth(1)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!((1, 13), (err.line, err.column));
+        assert!(blocks(line, &line[..9], '(', ')').next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_key_value() {
+        assert_eq!(
+            Ok(("aaa", "bbb ccc")),
+            key_value("aaa: bbb ccc", "aaa: bbb ccc", ":")
+        );
+        let err = key_value("aaa", "aaa", ": ").unwrap_err();
+        assert_eq!((1, 4), (err.line, err.column));
+    }
+}
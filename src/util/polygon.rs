@@ -0,0 +1,360 @@
+//! Simple polygons on integer coordinates, given by their vertices in order.
+
+use crate::util::{math::gcd, point::Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Twice the signed shoelace area, so it stays an integer. Positive when the vertices turn from
+/// the x axis towards the y axis, which is clockwise on screen where y points down.
+pub fn double_signed_area(vertices: &[Point]) -> i64 {
+    edges(vertices)
+        .map(|(a, b)| i64::from(a.x) * i64::from(b.y) - i64::from(b.x) * i64::from(a.y))
+        .sum()
+}
+
+/// Number of lattice points on the edges.
+pub fn boundary_points(vertices: &[Point]) -> u64 {
+    edges(vertices)
+        .map(|(a, b)| gcd(a.x.abs_diff(b.x) as usize, a.y.abs_diff(b.y) as usize) as u64)
+        .sum()
+}
+
+/// Number of lattice points strictly inside, by Pick's theorem `A = I + B / 2 - 1`. Zero for
+/// degenerate polygons, whose edges run back over each other.
+pub fn interior_points(vertices: &[Point]) -> u64 {
+    let double_area = i128::from(double_signed_area(vertices).unsigned_abs());
+    let boundary = i128::from(boundary_points(vertices));
+    ((double_area + 2 - boundary) / 2).max(0) as u64
+}
+
+/// `(b - a) × (p - a)`, positive when `p` is on the side of `a → b` the y axis is on.
+fn cross(a: (i128, i128), b: (i128, i128), p: (i128, i128)) -> i128 {
+    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+}
+
+/// [`locate`] for the point `(x, y) / scale`, to ask about points between lattice points.
+fn locate_scaled(vertices: &[Point], x: i64, y: i64, scale: i64) -> Location {
+    let p = (i128::from(x), i128::from(y));
+    let scaled = |v: Point| {
+        let scale = i128::from(scale);
+        (i128::from(v.x) * scale, i128::from(v.y) * scale)
+    };
+
+    let mut inside = false;
+    for (a, b) in edges(vertices) {
+        let (a, b) = (scaled(a), scaled(b));
+        let cross = cross(a, b, p);
+        if cross == 0
+            && (a.0.min(b.0)..=a.0.max(b.0)).contains(&p.0)
+            && (a.1.min(b.1)..=a.1.max(b.1)).contains(&p.1)
+        {
+            return Location::Boundary;
+        }
+        // Count the edges crossing the ray from `p` towards increasing x
+        if (a.1 > p.1) != (b.1 > p.1) && (cross > 0) == (b.1 > a.1) {
+            inside = !inside;
+        }
+    }
+
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// Where `point` lies, by casting a ray and counting the edges it crosses.
+pub fn locate(vertices: &[Point], point: Point) -> Location {
+    locate_scaled(vertices, i64::from(point.x), i64::from(point.y), 1)
+}
+
+/// How many times the polygon winds around `point`, zero outside. The sign follows
+/// [`double_signed_area`], and points on the boundary have no meaningful winding number.
+pub fn winding_number(vertices: &[Point], point: Point) -> i32 {
+    let p = (i128::from(point.x), i128::from(point.y));
+    let mut winding = 0;
+    for (a, b) in edges(vertices) {
+        let (a, b) = (
+            (i128::from(a.x), i128::from(a.y)),
+            (i128::from(b.x), i128::from(b.y)),
+        );
+        if a.1 <= p.1 {
+            if b.1 > p.1 && cross(a, b, p) > 0 {
+                winding += 1;
+            }
+        } else if b.1 <= p.1 && cross(a, b, p) < 0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Whether the axis-aligned rectangle with corners `a` and `b` lies inside the rectilinear
+/// polygon, its boundary included.
+pub fn rectangle_inside(vertices: &[Point], a: Point, b: Point) -> bool {
+    let (x1, x2) = (a.x.min(b.x), a.x.max(b.x));
+    let (y1, y2) = (a.y.min(b.y), a.y.max(b.y));
+
+    if x1 == x2 && y1 < y2 {
+        let transposed: Vec<Point> = vertices.iter().map(|v| Point::new(v.y, v.x)).collect();
+        return segment_inside(&transposed, x1, y1, y2);
+    }
+    if y1 == y2 {
+        return segment_inside(vertices, y1, x1, x2);
+    }
+
+    // No edge may cut through the open rectangle, which is then all on one side
+    for (p, q) in edges(vertices) {
+        debug_assert!(
+            p.x == q.x || p.y == q.y,
+            "{p:?} -> {q:?} is not axis-aligned"
+        );
+        if p.x.min(q.x) < x2 && p.x.max(q.x) > x1 && p.y.min(q.y) < y2 && p.y.max(q.y) > y1 {
+            return false;
+        }
+    }
+    let center = (i64::from(x1) + i64::from(x2), i64::from(y1) + i64::from(y2));
+    locate_scaled(vertices, center.0, center.1, 2) != Location::Outside
+}
+
+/// Whether the horizontal segment from `(x1, y)` to `(x2, y)` lies inside the polygon.
+fn segment_inside(vertices: &[Point], y: i32, x1: i32, x2: i32) -> bool {
+    // The segment can only change sides where it meets a vertex or crosses an edge
+    let mut stops = vec![x1, x2];
+    for (p, q) in edges(vertices) {
+        if p.y == y && (x1..=x2).contains(&p.x) {
+            stops.push(p.x);
+        }
+        if p.x == q.x && p.y.min(q.y) < y && y < p.y.max(q.y) && (x1..=x2).contains(&p.x) {
+            stops.push(p.x);
+        }
+    }
+    stops.sort_unstable();
+    stops.dedup();
+
+    let y = i64::from(y);
+    stops
+        .iter()
+        .all(|&x| locate_scaled(vertices, i64::from(x), y, 1) != Location::Outside)
+        && stops.windows(2).all(|w| {
+            let middle = i64::from(w[0]) + i64::from(w[1]);
+            locate_scaled(vertices, middle, 2 * y, 2) != Location::Outside
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{testing::cross_check, util::rng::Rng};
+
+    fn polygon(points: &[(i32, i32)]) -> Vec<Point> {
+        points.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    /// x-monotone polygon: a top chain left to right above y = 6, a bottom chain back below it.
+    /// With `rectilinear`, neighbouring vertices are joined by steps instead of slopes.
+    fn random_polygon(rng: &mut Rng, rectilinear: bool) -> Vec<Point> {
+        let columns = rng.range(2..=6) as usize;
+        let mut xs = vec![rng.range(-3..=3) as i32];
+        for _ in 1..columns {
+            xs.push(xs.last().unwrap() + rng.range(1..=4) as i32);
+        }
+
+        let mut top = Vec::new();
+        let mut bottom = Vec::new();
+        if rectilinear {
+            for i in 0..columns - 1 {
+                let h = rng.range(7..=12) as i32;
+                top.extend([Point::new(xs[i], h), Point::new(xs[i + 1], h)]);
+                let h = rng.range(0..=5) as i32;
+                bottom.extend([Point::new(xs[i], h), Point::new(xs[i + 1], h)]);
+            }
+        } else {
+            for &x in &xs {
+                top.push(Point::new(x, rng.range(7..=12) as i32));
+                bottom.push(Point::new(x, rng.range(0..=5) as i32));
+            }
+        }
+        bottom.reverse();
+        let mut vertices: Vec<Point> = top.into_iter().chain(bottom).collect();
+        vertices.dedup();
+        vertices
+    }
+
+    /// Every lattice point of the bounding box with its location.
+    fn lattice(vertices: &[Point]) -> Vec<(Point, Location)> {
+        let (x1, x2) = (
+            vertices.iter().map(|p| p.x).min(),
+            vertices.iter().map(|p| p.x).max(),
+        );
+        let (y1, y2) = (
+            vertices.iter().map(|p| p.y).min(),
+            vertices.iter().map(|p| p.y).max(),
+        );
+        (x1.unwrap()..=x2.unwrap())
+            .flat_map(|x| (y1.unwrap()..=y2.unwrap()).map(move |y| Point::new(x, y)))
+            .map(|p| (p, locate(vertices, p)))
+            .collect()
+    }
+
+    #[test]
+    fn test_area_and_pick() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(32, double_signed_area(&square));
+        assert_eq!(16, boundary_points(&square));
+        assert_eq!(9, interior_points(&square));
+
+        let reversed: Vec<Point> = square.iter().rev().copied().collect();
+        assert_eq!(-32, double_signed_area(&reversed));
+        assert_eq!(9, interior_points(&reversed));
+
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 4)]);
+        assert_eq!(12, boundary_points(&triangle));
+        assert_eq!(3, interior_points(&triangle));
+
+        let segment = polygon(&[(0, 0), (2, 0), (4, 0)]);
+        assert_eq!(0, double_signed_area(&segment));
+        assert_eq!(8, boundary_points(&segment));
+        assert_eq!(0, interior_points(&segment));
+    }
+
+    #[test]
+    fn test_locate() {
+        let l_shape = polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+        assert_eq!(Location::Inside, locate(&l_shape, Point::new(1, 3)));
+        assert_eq!(Location::Boundary, locate(&l_shape, Point::new(3, 2)));
+        assert_eq!(Location::Boundary, locate(&l_shape, Point::new(2, 2)));
+        assert_eq!(Location::Outside, locate(&l_shape, Point::new(3, 3)));
+        assert_eq!(Location::Outside, locate(&l_shape, Point::new(-1, 0)));
+
+        assert!(double_signed_area(&l_shape) > 0);
+        assert_eq!(1, winding_number(&l_shape, Point::new(1, 1)));
+        assert_eq!(0, winding_number(&l_shape, Point::new(3, 3)));
+
+        let reversed: Vec<Point> = l_shape.iter().rev().copied().collect();
+        assert!(double_signed_area(&reversed) < 0);
+        assert_eq!(-1, winding_number(&reversed, Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_pick_against_lattice() {
+        cross_check(
+            |rng| {
+                let rectilinear = rng.chance(1, 2);
+                random_polygon(rng, rectilinear)
+            },
+            |vertices| (interior_points(vertices), boundary_points(vertices)),
+            |vertices| {
+                let points = lattice(vertices);
+                let count = |l| points.iter().filter(|&&(_, loc)| loc == l).count() as u64;
+                (count(Location::Inside), count(Location::Boundary))
+            },
+        );
+    }
+
+    #[test]
+    fn test_winding_against_ray_casting() {
+        cross_check(
+            |rng| random_polygon(rng, false),
+            |vertices| {
+                lattice(vertices)
+                    .iter()
+                    .filter(|&&(_, loc)| loc != Location::Boundary)
+                    .map(|&(p, _)| winding_number(vertices, p) != 0)
+                    .collect::<Vec<_>>()
+            },
+            |vertices| {
+                lattice(vertices)
+                    .iter()
+                    .filter(|&&(_, loc)| loc != Location::Boundary)
+                    .map(|&(_, loc)| loc == Location::Inside)
+                    .collect::<Vec<_>>()
+            },
+        );
+    }
+
+    #[test]
+    fn test_rectangle_inside() {
+        let u_shape = polygon(&[
+            (0, 0),
+            (2, 0),
+            (2, 5),
+            (6, 5),
+            (6, 0),
+            (8, 0),
+            (8, 7),
+            (0, 7),
+        ]);
+        assert!(rectangle_inside(
+            &u_shape,
+            Point::new(0, 7),
+            Point::new(2, 0)
+        ));
+        assert!(rectangle_inside(
+            &u_shape,
+            Point::new(0, 7),
+            Point::new(8, 5)
+        ));
+        assert!(!rectangle_inside(
+            &u_shape,
+            Point::new(0, 0),
+            Point::new(8, 7)
+        ));
+        // Along the bottom edges, but across the opening of the U
+        assert!(rectangle_inside(
+            &u_shape,
+            Point::new(0, 0),
+            Point::new(2, 0)
+        ));
+        assert!(!rectangle_inside(
+            &u_shape,
+            Point::new(2, 0),
+            Point::new(6, 0)
+        ));
+        assert!(!rectangle_inside(
+            &u_shape,
+            Point::new(2, 3),
+            Point::new(6, 3)
+        ));
+        assert!(rectangle_inside(
+            &u_shape,
+            Point::new(2, 5),
+            Point::new(6, 5)
+        ));
+    }
+
+    #[test]
+    fn test_rectangle_inside_against_half_lattice() {
+        cross_check(
+            |rng| {
+                let vertices = random_polygon(rng, true);
+                let a = *rng.choose(&vertices);
+                let b = *rng.choose(&vertices);
+                (vertices, a, b)
+            },
+            |(vertices, a, b)| rectangle_inside(vertices, *a, *b),
+            |(vertices, a, b)| {
+                // Every point of the rectangle on a grid of half units
+                let (x1, x2) = (2 * a.x.min(b.x), 2 * a.x.max(b.x));
+                let (y1, y2) = (2 * a.y.min(b.y), 2 * a.y.max(b.y));
+                (x1..=x2).all(|x| {
+                    (y1..=y2).all(|y| {
+                        locate_scaled(vertices, i64::from(x), i64::from(y), 2) != Location::Outside
+                    })
+                })
+            },
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate::util::{grid::Grid, point::Point};
+
+/// Summed-area table of a grid, answers rectangle sums in O(1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSum2D {
+    width: i32,
+    height: i32,
+    /// One row and column of zeros, then the sum of every cell up to and including (x, y) at
+    /// `(y + 1) * (width + 1) + x + 1`.
+    sums: Vec<i64>,
+}
+
+impl PrefixSum2D {
+    pub fn new<T>(grid: &Grid<T>, value: impl Fn(&T) -> i64) -> Self {
+        let stride = grid.width as usize + 1;
+        let mut sums = vec![0; stride * (grid.height as usize + 1)];
+        for (p, e) in grid.cells() {
+            let idx = (p.y as usize + 1) * stride + p.x as usize + 1;
+            sums[idx] = value(e) + sums[idx - 1] + sums[idx - stride] - sums[idx - stride - 1];
+        }
+
+        PrefixSum2D {
+            width: grid.width,
+            height: grid.height,
+            sums,
+        }
+    }
+
+    /// Counts the cells matching `predicate`.
+    pub fn count<T>(grid: &Grid<T>, predicate: impl Fn(&T) -> bool) -> Self {
+        Self::new(grid, |e| i64::from(predicate(e)))
+    }
+
+    fn at(&self, x: i32, y: i32) -> i64 {
+        self.sums[(y + 1) as usize * (self.width as usize + 1) + (x + 1) as usize]
+    }
+
+    /// Sum over the rectangle with corners `a` and `b`, both included and in any order.
+    /// Parts outside of the grid count as zero.
+    pub fn sum(&self, a: Point, b: Point) -> i64 {
+        let (x1, x2) = (a.x.min(b.x).max(0), a.x.max(b.x).min(self.width - 1));
+        let (y1, y2) = (a.y.min(b.y).max(0), a.y.max(b.y).min(self.height - 1));
+        if x1 > x2 || y1 > y2 {
+            return 0;
+        }
+        self.at(x2, y2) - self.at(x1 - 1, y2) - self.at(x2, y1 - 1) + self.at(x1 - 1, y1 - 1)
+    }
+
+    pub fn total(&self) -> i64 {
+        self.sums.last().copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sum() {
+        let grid = Grid::parse("123\n456\n789\n").unwrap();
+        let sums = PrefixSum2D::new(&grid, |&e| i64::from(e - b'0'));
+
+        assert_eq!(45, sums.total());
+        assert_eq!(5, sums.sum(Point::new(1, 1), Point::new(1, 1)));
+        assert_eq!(5 + 6 + 8 + 9, sums.sum(Point::new(2, 2), Point::new(1, 1)));
+        assert_eq!(1 + 4 + 7, sums.sum(Point::new(0, 2), Point::new(0, 0)));
+    }
+
+    #[test]
+    fn test_edges() {
+        let grid = Grid::parse("#.#\n.##\n").unwrap();
+        let walls = PrefixSum2D::count(&grid, |&e| e == b'#');
+
+        assert_eq!(4, walls.sum(Point::new(-5, -5), Point::new(10, 10)));
+        assert_eq!(2, walls.sum(Point::new(2, -1), Point::new(7, 1)));
+        assert_eq!(0, walls.sum(Point::new(3, 0), Point::new(4, 1)));
+        assert_eq!(0, walls.sum(Point::new(0, -3), Point::new(2, -1)));
+    }
+}
@@ -0,0 +1,407 @@
+//! Graph searches over any state type, driven by a successor closure.
+//!
+//! Every search stops at the first state `is_goal` accepts, pass `|_| false` to explore all that
+//! is reachable. The `grid_*` variants track `Point`s with dense grids and a visited bitmap.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+};
+
+use bitvec::prelude::*;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::util::{grid::Grid, point::Point};
+
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    /// Distance from the closest start of every reached state.
+    pub dist: HashMap<S, u64>,
+    /// State every reached state was reached from, starts have none.
+    pub prev: HashMap<S, S>,
+    /// First state accepted by `is_goal`, if any.
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new() -> Self {
+        Search {
+            dist: HashMap::default(),
+            prev: HashMap::default(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.dist.get(state).copied()
+    }
+
+    pub fn goal_distance(&self) -> Option<u64> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// States from a start to `target`, both included.
+    pub fn path(&self, target: &S) -> Option<Vec<S>> {
+        self.dist.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(prev) = self.prev.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search, distances count edges.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.dist.contains_key(&start) {
+            search.dist.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, dist)) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for next in successors(&state) {
+            if !search.dist.contains_key(&next) {
+                search.dist.insert(next.clone(), dist + 1);
+                search.prev.insert(next.clone(), state.clone());
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+    search
+}
+
+/// Depth-first search. Distances are depths in the search tree, not shortest distances.
+pub fn dfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut stack: Vec<(S, Option<S>, u64)> = starts.into_iter().map(|s| (s, None, 0)).collect();
+    stack.reverse();
+
+    while let Some((state, prev, dist)) = stack.pop() {
+        if search.dist.contains_key(&state) {
+            continue;
+        }
+        search.dist.insert(state.clone(), dist);
+        if let Some(prev) = prev {
+            search.prev.insert(state.clone(), prev);
+        }
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let first = stack.len();
+        for next in successors(&state) {
+            if !search.dist.contains_key(&next) {
+                stack.push((next, Some(state.clone()), dist + 1));
+            }
+        }
+        // Visit successors in the order they were given
+        stack[first..].reverse();
+    }
+    search
+}
+
+/// Dijkstra's algorithm, `successors` yields states with the cost of reaching them.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to a goal. States whose
+/// distance improves after their expansion are expanded again, so it need not be consistent.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new();
+    // The heap holds indices into `states`, so states need no ordering
+    let mut states = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if !search.dist.contains_key(&start) {
+            search.dist.insert(start.clone(), 0);
+            heap.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, dist, idx))) = heap.pop() {
+        let state = states[idx].clone();
+        if search.dist[&state] < dist {
+            continue;
+        }
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (next, cost) in successors(&state) {
+            let next_dist = dist + cost;
+            if search.dist.get(&next).is_none_or(|&d| next_dist < d) {
+                search.dist.insert(next.clone(), next_dist);
+                search.prev.insert(next.clone(), state.clone());
+                heap.push(Reverse((
+                    next_dist + heuristic(&next),
+                    next_dist,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+    search
+}
+
+/// Result of a search over the cells of a grid.
+#[derive(Debug, Clone)]
+pub struct GridSearch {
+    /// Cells already expanded (Dijkstra) or discovered (BFS), row by row.
+    pub visited: BitVec,
+    /// Distance of every cell, `u64::MAX` where unreached.
+    pub dist: Grid<u64>,
+    pub prev: Grid<Option<Point>>,
+    pub goal: Option<Point>,
+}
+
+impl GridSearch {
+    fn new<T>(grid: &Grid<T>) -> Self {
+        GridSearch {
+            visited: bitvec![0; grid.data.len()],
+            dist: Grid::new(grid.width, grid.height, u64::MAX),
+            prev: Grid::new(grid.width, grid.height, None),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, point: Point) -> Option<u64> {
+        self.dist.get(point).copied().filter(|&d| d != u64::MAX)
+    }
+
+    pub fn goal_distance(&self) -> Option<u64> {
+        self.distance(self.goal?)
+    }
+
+    /// Number of reached cells.
+    pub fn reached(&self) -> usize {
+        self.dist.data.iter().filter(|&&d| d != u64::MAX).count()
+    }
+
+    /// Cells from a start to `target`, both included.
+    pub fn path(&self, target: Point) -> Option<Vec<Point>> {
+        self.distance(target)?;
+        let mut path = vec![target];
+        while let Some(prev) = self.prev[*path.last().unwrap()] {
+            path.push(prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// [`bfs`] over the cells of `grid`, successors outside of it are ignored.
+pub fn grid_bfs<T, I>(
+    grid: &Grid<T>,
+    starts: impl IntoIterator<Item = Point>,
+    mut successors: impl FnMut(Point) -> I,
+    mut is_goal: impl FnMut(Point) -> bool,
+) -> GridSearch
+where
+    I: IntoIterator<Item = Point>,
+{
+    let mut search = GridSearch::new(grid);
+    let idx = |point: Point| (point.y * grid.width + point.x) as usize;
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if grid.contains(start) && !search.visited.replace(idx(start), true) {
+            search.dist[start] = 0;
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(point) = queue.pop_front() {
+        if is_goal(point) {
+            search.goal = Some(point);
+            break;
+        }
+        let dist = search.dist[point];
+        for next in successors(point) {
+            if grid.contains(next) && !search.visited.replace(idx(next), true) {
+                search.dist[next] = dist + 1;
+                search.prev[next] = Some(point);
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// [`dijkstra`] over the cells of `grid`, successors outside of it are ignored.
+pub fn grid_dijkstra<T, I>(
+    grid: &Grid<T>,
+    starts: impl IntoIterator<Item = Point>,
+    mut successors: impl FnMut(Point) -> I,
+    mut is_goal: impl FnMut(Point) -> bool,
+) -> GridSearch
+where
+    I: IntoIterator<Item = (Point, u64)>,
+{
+    let mut search = GridSearch::new(grid);
+    let idx = |point: Point| (point.y * grid.width + point.x) as usize;
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if grid.contains(start) {
+            search.dist[start] = 0;
+            heap.push(Reverse((0, start.y, start.x)));
+        }
+    }
+
+    while let Some(Reverse((dist, y, x))) = heap.pop() {
+        let point = Point::new(x, y);
+        if search.visited.replace(idx(point), true) {
+            continue;
+        }
+        if is_goal(point) {
+            search.goal = Some(point);
+            break;
+        }
+        for (next, cost) in successors(point) {
+            if grid.contains(next) && dist + cost < search.dist[next] {
+                search.dist[next] = dist + cost;
+                search.prev[next] = Some(point);
+                heap.push(Reverse((dist + cost, next.y, next.x)));
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAZE: &str = "S.#.....
+.##.###.
+....#...
+.##...#E
+";
+
+    fn open(grid: &Grid<u8>) -> impl Fn(Point) -> Vec<Point> + '_ {
+        |p| grid.neighbors4(p).filter(|&n| grid[n] != b'#').collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        // Collatz-like implicit graph: n -> n + 1, n * 2
+        let search = bfs([1u64], |&n| [n + 1, n * 2], |&n| n == 10);
+        assert_eq!(Some(10), search.goal);
+        assert_eq!(Some(4), search.goal_distance());
+        assert_eq!(Some(vec![1, 2, 4, 5, 10]), search.path(&10));
+        assert_eq!(None, search.path(&11));
+    }
+
+    #[test]
+    fn test_dfs() {
+        let search = dfs([0u32], |&n| (n < 3).then_some(n + 1), |_| false);
+        assert_eq!(4, search.dist.len());
+        assert_eq!(Some(vec![0, 1, 2, 3]), search.path(&3));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Walking costs 1, a jump of 5 costs 3
+        let successors = |&n: &i64| [(n + 1, 1), (n - 1, 1), (n + 5, 3)];
+        let search = dijkstra([0i64], successors, |&n| n == 17);
+        assert_eq!(Some(11), search.goal_distance());
+
+        let search = astar(
+            [0i64],
+            successors,
+            |&n| (17 - n).max(0) as u64 * 3 / 5,
+            |&n| n == 17,
+        );
+        assert_eq!(Some(11), search.goal_distance());
+        let path = search.path(&17).unwrap();
+        assert_eq!((0, 17), (path[0], *path.last().unwrap()));
+    }
+
+    #[test]
+    fn test_astar_inconsistent_heuristic() {
+        // The heuristic of A is admissible but makes B look settled at 3 before A reaches it at 2
+        let successors = |&n: &char| match n {
+            'S' => vec![('A', 1), ('B', 3)],
+            'A' => vec![('B', 1)],
+            'B' => vec![('G', 3)],
+            _ => vec![],
+        };
+        let heuristic = |&n: &char| if n == 'A' { 4 } else { 0 };
+        let search = astar(['S'], successors, heuristic, |&n| n == 'G');
+        assert_eq!(Some(5), search.goal_distance());
+        assert_eq!(Some(vec!['S', 'A', 'B', 'G']), search.path(&'G'));
+    }
+
+    #[test]
+    fn test_grid_search() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let (start, end) = (grid.find(b'S').unwrap(), grid.find(b'E').unwrap());
+
+        let by_bfs = grid_bfs(&grid, [start], open(&grid), |p| p == end);
+        let generic = bfs([start], |&p| open(&grid)(p), |&p| p == end);
+        assert_eq!(generic.goal_distance(), by_bfs.goal_distance());
+        assert_eq!(Some(12), by_bfs.goal_distance());
+        let path = by_bfs.path(end).unwrap();
+        assert_eq!(13, path.len());
+        assert!(path.iter().all(|&p| grid[p] != b'#'));
+
+        let by_dijkstra = grid_dijkstra(
+            &grid,
+            [start],
+            |p| open(&grid)(p).into_iter().map(|n| (n, 2)),
+            |_| false,
+        );
+        assert_eq!(Some(24), by_dijkstra.distance(end));
+        assert_eq!(None, by_dijkstra.distance(Point::new(2, 0)));
+        assert_eq!(
+            grid.data.iter().filter(|&&e| e != b'#').count(),
+            by_dijkstra.reached()
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::util::{
+    grid::{Grid, render_map},
+    point::Point,
+};
+
+/// Unbounded grid keyed by `Point`, negative coordinates included. Cells never written read as
+/// `default`.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    default: T,
+    /// Top-left and bottom-right corners of the smallest box holding every set cell.
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            cells: HashMap::default(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Number of set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn is_set(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// The cell at `point`, or the default if it was never set.
+    pub fn get(&self, point: Point) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.grow(point);
+        self.cells.insert(point, value)
+    }
+
+    /// Unsets the cell, shrinking the bounds if it was on their edge.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let removed = self.cells.remove(&point)?;
+        if let Some((min, max)) = self.bounds
+            && ([min.x, max.x].contains(&point.x) || [min.y, max.y].contains(&point.y))
+        {
+            self.bounds = None;
+            let points: Vec<Point> = self.cells.keys().copied().collect();
+            points.into_iter().for_each(|p| self.grow(p));
+        }
+        Some(removed)
+    }
+
+    fn grow(&mut self, point: Point) {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+        });
+    }
+
+    /// Top-left and bottom-right corners of the smallest box holding every set cell, both
+    /// included. `None` while no cell is set.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Set cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&p, e)| (p, e))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Dense copy of the bounding box, with the position of its top-left corner.
+    pub fn to_grid(&self) -> (Grid<T>, Point) {
+        let Some((min, max)) = self.bounds else {
+            return (Grid::new(0, 0, self.default.clone()), Point::new(0, 0));
+        };
+        let mut grid = Grid::new(max.x - min.x + 1, max.y - min.y + 1, self.default.clone());
+        for (&p, e) in &self.cells {
+            grid[p - min] = e.clone();
+        }
+        (grid, min)
+    }
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// Sets every cell of `grid` that differs from `default`.
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self {
+        let mut sparse = SparseGrid::new(default);
+        for (p, e) in grid.cells() {
+            if *e != sparse.default {
+                sparse.insert(p, e.clone());
+            }
+        }
+        sparse
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+/// Writing sets the cell, starting from the default.
+impl<T: Clone> IndexMut<Point> for SparseGrid<T> {
+    fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        self.grow(index);
+        self.cells
+            .entry(index)
+            .or_insert_with(|| self.default.clone())
+    }
+}
+
+impl fmt::Display for SparseGrid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return render_map(f, 0, 0, |_, _| self.default);
+        };
+        render_map(f, max.x - min.x + 1, max.y - min.y + 1, |x, y| {
+            *self.get(min + Point::new(x, y))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_default_and_growth() {
+        let mut grid = SparseGrid::new(b'.');
+        assert_eq!(None, grid.bounds());
+        assert_eq!(b'.', grid[Point::new(-1_000_000, 7)]);
+
+        grid[Point::new(-2, 3)] = b'#';
+        grid.insert(Point::new(4, -1), b'#');
+        assert_eq!(2, grid.len());
+        assert_eq!(Some((Point::new(-2, -1), Point::new(4, 3))), grid.bounds());
+
+        assert_eq!(Some(b'#'), grid.remove(Point::new(4, -1)));
+        assert_eq!(Some((Point::new(-2, 3), Point::new(-2, 3))), grid.bounds());
+        assert!(!grid.is_set(Point::new(4, -1)));
+    }
+
+    #[test]
+    fn test_grid_round_trip() {
+        let dense = Grid::parse("#..\n.#.\n..#\n").unwrap();
+        let sparse = SparseGrid::from_grid(&dense, b'.');
+        assert_eq!(3, sparse.len());
+        assert_eq!((dense.clone(), Point::new(0, 0)), sparse.to_grid());
+        assert_eq!(dense.to_string(), sparse.to_string());
+    }
+
+    #[test]
+    fn test_display_negative() {
+        let mut grid = SparseGrid::new(b' ');
+        grid.insert(Point::new(-1, -1), b'a');
+        grid.insert(Point::new(1, 0), b'b');
+        assert_eq!("Map View (3x2):\na  \n  b\n", grid.to_string());
+
+        let (dense, offset) = grid.to_grid();
+        assert_eq!(Point::new(-1, -1), offset);
+        assert_eq!(b'b', dense[Point::new(1, 0) - offset]);
+    }
+}
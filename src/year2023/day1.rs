@@ -1,7 +1,11 @@
+use std::fmt::Display;
+
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-pub fn parse(input: &str) -> Vec<&str> {
-    input.lines().collect()
+use crate::{error::ParseError, solution::Solution};
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.lines().collect())
 }
 
 pub fn part_1(input: &[&str]) -> u32 {
@@ -72,6 +76,24 @@ fn str_to_digits(line: &str) -> Vec<u32> {
     digits
 }
 
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::year2023::day1::{parse, part_1, part_2};
@@ -92,13 +114,13 @@ zoneight234
 ";
     #[test]
     fn test_parse_input() {
-        let val = part_1(&parse(SAMPLE_INPUT));
+        let val = part_1(&parse(SAMPLE_INPUT).unwrap());
         assert_eq!(val, 142)
     }
 
     #[test]
     fn test_digit_to_u32() {
-        let val = part_2(&parse(SAMPLE_INPUT_2));
+        let val = part_2(&parse(SAMPLE_INPUT_2).unwrap());
         assert_eq!(val, 281)
     }
 }
@@ -1,102 +1,205 @@
-use rustc_hash::FxHashSet as HashSet;
-use std::collections::VecDeque;
-
-use crate::util::{
-    grid::Grid,
-    point::{EAST, NORTH, SOUTH, WEST},
+use std::fmt::{self, Display};
+
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::{
+        grid::Grid,
+        point::{EAST, NORTH, Point, SOUTH, WEST},
+        polygon,
+    },
 };
 
-pub fn part_1(input: &str) -> u64 {
-    let grid = Grid::parse(input);
-    let starting_position = grid.find(b'S').unwrap();
-
-    let mut q = VecDeque::new();
-    q.push_back(starting_position);
-
-    let mut visited = HashSet::default();
-    visited.insert(starting_position);
-
-    while let Some(curr_node) = q.pop_front() {
-        let curr_char = grid[curr_node];
-
-        let directions = match curr_char {
-            b'|' => vec![NORTH, SOUTH],
-            b'-' => vec![EAST, WEST],
-            b'L' => vec![NORTH, EAST],
-            b'J' => vec![NORTH, WEST],
-            b'7' => vec![SOUTH, WEST],
-            b'F' => vec![SOUTH, EAST],
-            b'S' => vec![NORTH, SOUTH, EAST, WEST],
-            _ => continue,
-        };
-
-        for d in directions {
-            let neighbor_point = curr_node + d;
-
-            if let Some(&neighbor_char) = grid.get(neighbor_point) {
-                let is_valid_connection = matches!(
-                    (d, neighbor_char),
-                    (NORTH, b'|' | b'7' | b'F' | b'S')
-                        | (SOUTH, b'|' | b'L' | b'J' | b'S')
-                        | (EAST, b'-' | b'J' | b'7' | b'S')
-                        | (WEST, b'-' | b'L' | b'F' | b'S')
-                );
-
-                if is_valid_connection && !visited.contains(&neighbor_point) {
-                    visited.insert(neighbor_point);
-                    q.push_back(neighbor_point);
-                }
-            }
+const PIPES: [(u8, [Point; 2]); 6] = [
+    (b'|', [NORTH, SOUTH]),
+    (b'-', [EAST, WEST]),
+    (b'L', [NORTH, EAST]),
+    (b'J', [NORTH, WEST]),
+    (b'7', [SOUTH, WEST]),
+    (b'F', [SOUTH, EAST]),
+];
+
+fn connections(tile: u8) -> Option<[Point; 2]> {
+    PIPES.iter().find(|(t, _)| *t == tile).map(|&(_, d)| d)
+}
+
+fn pipe(a: Point, b: Point) -> u8 {
+    PIPES
+        .iter()
+        .find(|(_, d)| *d == [a, b] || *d == [b, a])
+        .map(|&(t, _)| t)
+        .unwrap()
+}
+
+/// The loop of pipes through `S`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipeLoop {
+    /// Tiles of the loop in order, starting at `S`.
+    pub tiles: Vec<Point>,
+    /// The pipe hidden under `S`.
+    pub start_pipe: u8,
+    /// Pipes of the loop, `S` replaced by its pipe, and `.` everywhere else.
+    map: Grid<u8>,
+}
+
+impl PipeLoop {
+    /// `None` without a loop through `S`.
+    pub fn new(grid: &Grid<u8>) -> Option<Self> {
+        let start = grid.find(b'S')?;
+        [NORTH, SOUTH, EAST, WEST]
+            .into_iter()
+            .find_map(|first| Self::walk(grid, start, first))
+    }
+
+    /// Follows the pipes leaving `start` towards `first`, until they come back to it.
+    fn walk(grid: &Grid<u8>, start: Point, first: Point) -> Option<Self> {
+        let mut map = Grid::new(grid.width, grid.height, b'.');
+        let mut tiles = vec![start];
+        let (mut position, mut direction) = (start + first, first);
+
+        while position != start {
+            let [a, b] = connections(*grid.get(position)?)?;
+            let back = direction * -1;
+            direction = match back {
+                _ if back == a => b,
+                _ if back == b => a,
+                _ => return None,
+            };
+            map[position] = grid[position];
+            tiles.push(position);
+            position += direction;
         }
+
+        let start_pipe = pipe(first, direction * -1);
+        map[start] = start_pipe;
+        Some(PipeLoop {
+            tiles,
+            start_pipe,
+            map,
+        })
     }
 
-    visited.len() as u64 / 2
+    /// Steps along the loop to the tile farthest from `S`.
+    pub fn farthest(&self) -> usize {
+        self.tiles.len() / 2
+    }
+
+    /// Number of tiles enclosed by the loop.
+    pub fn enclosed(&self) -> u64 {
+        polygon::interior_points(&self.tiles)
+    }
 }
 
-pub fn part_2(input: &str) -> u64 {
-    let grid = Grid::parse(input);
-
-    let starting_position = grid.find(b'S').unwrap();
-
-    let mut q = VecDeque::new();
-    q.push_back(starting_position);
-
-    let mut visited = HashSet::default();
-    visited.insert(starting_position);
-
-    while let Some(curr_node) = q.pop_front() {
-        let curr_char = grid[curr_node];
-
-        let directions = match curr_char {
-            b'|' => vec![NORTH, SOUTH],
-            b'-' => vec![EAST, WEST],
-            b'L' => vec![NORTH, EAST],
-            b'J' => vec![NORTH, WEST],
-            b'7' => vec![SOUTH, WEST],
-            b'F' => vec![SOUTH, EAST],
-            b'S' => vec![NORTH, SOUTH, EAST, WEST],
-            _ => continue,
-        };
-
-        for d in directions {
-            let neighbor_point = curr_node + d;
-
-            if let Some(&neighbor_char) = grid.get(neighbor_point) {
-                let is_valid_connection = matches!(
-                    (d, neighbor_char),
-                    (NORTH, b'|' | b'7' | b'F' | b'S')
-                        | (SOUTH, b'|' | b'L' | b'J' | b'S')
-                        | (EAST, b'-' | b'J' | b'7' | b'S')
-                        | (WEST, b'-' | b'L' | b'F' | b'S')
-                );
-
-                if is_valid_connection && !visited.contains(&neighbor_point) {
-                    visited.insert(neighbor_point);
-                    q.push_back(neighbor_point);
+/// The loop with the tiles inside it marked `I` and the ones outside `O`.
+impl Display for PipeLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = self.map.clone();
+        for y in 0..map.height {
+            // Crossing a pipe going north swaps sides
+            let mut inside = false;
+            for x in 0..map.width {
+                let tile = &mut map[Point::new(x, y)];
+                match *tile {
+                    b'|' | b'L' | b'J' => inside = !inside,
+                    b'.' => *tile = if inside { b'I' } else { b'O' },
+                    _ => {}
                 }
             }
         }
+        write!(f, "{map}")
+    }
+}
+
+pub fn parse(input: &str) -> Result<PipeLoop, ParseError> {
+    let Some(start) = input.find('S') else {
+        return Err(ParseError::end_of_input(input, "starting tile `S`"));
+    };
+    let grid = Grid::parse(input)?;
+    PipeLoop::new(&grid)
+        .ok_or_else(|| ParseError::at(input, &input[start..start + 1], "loop through `S`"))
+}
+
+pub fn part_1(pipe_loop: &PipeLoop) -> u64 {
+    pipe_loop.farthest() as u64
+}
+
+pub fn part_2(pipe_loop: &PipeLoop) -> u64 {
+    pipe_loop.enclosed()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = PipeLoop;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
     }
 
-    todo!()
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::year2023::day10;
+
+    const SAMPLE_INPUT: &str = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+    const SAMPLE_INPUT_ENCLOSED: &str = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+
+    #[test]
+    fn test_part_1() {
+        let pipe_loop = day10::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(8, day10::part_1(&pipe_loop));
+        assert_eq!(b'F', pipe_loop.start_pipe);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let pipe_loop = day10::parse(SAMPLE_INPUT_ENCLOSED).unwrap();
+        assert_eq!(4, day10::part_2(&pipe_loop));
+    }
+
+    #[test]
+    fn test_display() {
+        let pipe_loop = day10::parse(SAMPLE_INPUT_ENCLOSED).unwrap();
+        assert_eq!(
+            "Map View (10x9):
+OOOOOOOOOO
+OF------7O
+O|F----7|O
+O||OOOO||O
+O||OOOO||O
+O|L-7F-J|O
+O|II||II|O
+OL--JL--JO
+OOOOOOOOOO
+",
+            pipe_loop.to_string()
+        );
+    }
+
+    #[test]
+    fn test_no_loop() {
+        let err = day10::parse("S-7\n..|\n").unwrap_err();
+        assert_eq!((1, 1), (err.line, err.column));
+    }
 }
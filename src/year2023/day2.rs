@@ -1,79 +1,114 @@
-pub fn parse(input: &str) -> Vec<&str> {
-    input.lines().collect()
+use std::fmt::Display;
+
+use crate::{
+    error::{ParseError, parse_at},
+    solution::Solution,
+    util::parse::key_value,
+};
+
+/// Every game as the list of its draws.
+pub fn parse(input: &str) -> Result<Vec<Vec<Cube>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, game_data) = key_value(input, line, ": ")?;
+
+            game_data
+                .split(';')
+                .map(|draw| Cube::parse(input, draw.trim()))
+                .collect()
+        })
+        .collect()
 }
 
 const CONFIG: (u32, u32, u32) = (12, 13, 14); // r g b
 
-pub fn part_1(input: &[&str]) -> usize {
-    input
+pub fn part_1(games: &[Vec<Cube>]) -> usize {
+    games
         .iter()
         .enumerate()
         .map(
-            |(game_id, game_line)| {
-                if possible(game_line) { game_id + 1 } else { 0 }
+            |(game_id, draws)| {
+                if possible(draws) { game_id + 1 } else { 0 }
             },
         )
         .sum()
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-struct Cube(u32, u32, u32); // r g b
+pub struct Cube(u32, u32, u32); // r g b
 
-impl From<&str> for Cube {
-    fn from(line: &str) -> Self {
+impl Cube {
+    /// Parses a draw such as `3 blue, 4 red`, `draw` must be a subslice of `input`.
+    fn parse(input: &str, draw: &str) -> Result<Self, ParseError> {
         let mut red = 0;
         let mut green = 0;
         let mut blue = 0;
 
-        for pair in line.split(", ") {
+        for pair in draw.split(", ") {
             let mut parts = pair.split_whitespace();
 
-            let count_str = parts.next().unwrap();
-            let count = count_str.parse::<u32>().unwrap();
+            let count_str = parts
+                .next()
+                .ok_or_else(|| ParseError::at(input, pair, "cube count"))?;
+            let count = parse_at::<u32>(input, count_str, "cube count")?;
 
-            let color = parts.next().unwrap();
+            let color = parts
+                .next()
+                .ok_or_else(|| ParseError::at(input, &pair[pair.len()..], "cube color"))?;
 
             match color {
                 "red" => red = count,
                 "green" => green = count,
                 "blue" => blue = count,
-                _ => (),
+                _ => return Err(ParseError::at(input, color, "`red`, `green` or `blue`")),
             }
         }
-        Self(red, green, blue)
+        Ok(Self(red, green, blue))
     }
 }
 
-fn possible(game: &str) -> bool {
-    let (_, game_data) = game.split_once(": ").unwrap();
-    game_data.split(';').map(|s| s.trim()).all(|x| {
-        let cube = Cube::from(x);
-        cube.0 <= CONFIG.0 && cube.1 <= CONFIG.1 && cube.2 <= CONFIG.2
-    })
+fn possible(draws: &[Cube]) -> bool {
+    draws
+        .iter()
+        .all(|cube| cube.0 <= CONFIG.0 && cube.1 <= CONFIG.1 && cube.2 <= CONFIG.2)
 }
 
-pub fn part_2(input: &[&str]) -> u32 {
-    // input.iter().enumerate().map(todo!()).sum()
-    input
+pub fn part_2(games: &[Vec<Cube>]) -> u32 {
+    games
         .iter()
-        .map(|line| {
-            let (_, game_data) = line.split_once(": ").unwrap();
-            let max_cube = game_data.split(';').map(|s| s.trim()).map(Cube::from).fold(
-                Cube(0, 0, 0),
-                |acc, cube| {
-                    Cube(
-                        acc.0.max(cube.0), // Max red
-                        acc.1.max(cube.1), // Max green
-                        acc.2.max(cube.2), // Max blue
-                    )
-                },
-            );
+        .map(|draws| {
+            let max_cube = draws.iter().fold(Cube(0, 0, 0), |acc, cube| {
+                Cube(
+                    acc.0.max(cube.0), // Max red
+                    acc.1.max(cube.1), // Max green
+                    acc.2.max(cube.2), // Max blue
+                )
+            });
 
             max_cube.0 * max_cube.1 * max_cube.2
         })
         .sum()
 }
 
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Vec<Cube>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::year2023::day2::{Cube, parse, part_1};
@@ -86,14 +121,14 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
     #[test]
     fn test_part_1() {
-        let result = part_1(&parse(SAMPLE_INPUT_1));
+        let result = part_1(&parse(SAMPLE_INPUT_1).unwrap());
         assert_eq!(result, 8)
     }
 
     #[test]
     fn test_cube_from_str() {
         let draw_string_1 = "3 blue, 4 red";
-        let cube = Cube::from(draw_string_1);
+        let cube = Cube::parse(draw_string_1, draw_string_1).unwrap();
         assert_eq!(cube, Cube(4, 0, 3));
     }
 }
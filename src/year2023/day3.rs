@@ -1,43 +1,30 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, fmt::Display};
 
-use crate::util::point::DIRECTIONS;
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::{grid::Grid, point::Point},
+};
 
-pub fn parse(input: &str) -> Vec<&[u8]> {
-    input.lines().map(str::as_bytes).collect()
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    if input.trim().is_empty() {
+        return Err(ParseError::end_of_input(input, "engine schematic"));
+    }
+    Grid::parse(input)
 }
 
-// optimization strat
-// for imporving cache locality we can represent a 2d array into a 1d array where instead of
-// indexing as [x][y] we do [width*x+y]
-
-pub fn part_1(input: &[&[u8]]) -> usize {
-    let n = input.len();
-    let m = input[0].len();
-
+pub fn part_1(grid: &Grid<u8>) -> usize {
     let mut nums = Vec::new();
     let mut seen = HashSet::new();
 
-    for y in 0..n {
-        for x in 0..m {
-            if input[y][x] != b'.'
-                && input[y][x].is_ascii_graphic()
-                && !input[y][x].is_ascii_digit()
-            {
-                for p in &DIRECTIONS {
-                    let (dx, dy) = p.coords();
-                    let nx = x as i32 + dx;
-                    let ny = y as i32 + dy;
-
-                    if nx >= 0 && ny >= 0 && (ny as usize) < n && (nx as usize) < m {
-                        let (nx, ny) = (nx as usize, ny as usize);
-
-                        if input[ny][nx].is_ascii_digit() {
-                            let (num, start_x) = get_whole_number(input, nx, ny);
-
-                            if seen.insert((ny, start_x)) {
-                                nums.push(num);
-                            }
-                        }
+    for (point, &e) in grid.cells() {
+        if e != b'.' && e.is_ascii_graphic() && !e.is_ascii_digit() {
+            for (next, digit) in grid.neighbor_cells8(point) {
+                if digit.is_ascii_digit() {
+                    let (num, start) = get_whole_number(grid, next);
+
+                    if seen.insert(start) {
+                        nums.push(num);
                     }
                 }
             }
@@ -47,57 +34,48 @@ pub fn part_1(input: &[&[u8]]) -> usize {
     nums.iter().sum()
 }
 
-fn get_whole_number(input: &[&[u8]], x: usize, y: usize) -> (usize, usize) {
+/// The number with a digit at `point`, and where it starts.
+fn get_whole_number(grid: &Grid<u8>, point: Point) -> (usize, Point) {
+    let row = &grid.data[(point.y * grid.width) as usize..][..grid.width as usize];
+    let x = point.x as usize;
+
     let mut start = x;
-    while start > 0 && input[y][start - 1].is_ascii_digit() {
+    while start > 0 && row[start - 1].is_ascii_digit() {
         start -= 1;
     }
 
     let mut end = x;
-    while end < input[y].len() && input[y][end].is_ascii_digit() {
+    while end < row.len() && row[end].is_ascii_digit() {
         end += 1;
     }
 
-    let num_str = unsafe { std::str::from_utf8_unchecked(&input[y][start..end]) };
-    let num = num_str.parse::<usize>().unwrap();
+    let num = row[start..end]
+        .iter()
+        .fold(0, |num, &d| num * 10 + usize::from(d - b'0'));
 
-    (num, start)
+    (num, Point::new(start as i32, point.y))
 }
 
-pub fn part_2(input: &[&[u8]]) -> usize {
-    let n = input.len();
-    let m = input[0].len();
-
+pub fn part_2(grid: &Grid<u8>) -> usize {
     let mut nums = Vec::new();
     let mut seen = HashSet::new();
 
-    for y in 0..n {
-        for x in 0..m {
-            if input[y][x] == b'*' {
-                let mut adjacence = Vec::new();
+    for (point, &e) in grid.cells() {
+        if e == b'*' {
+            let mut adjacence = Vec::new();
 
-                for p in &DIRECTIONS {
-                    let (dx, dy) = p.coords();
-                    let nx = x as i32 + dx;
-                    let ny = y as i32 + dy;
+            for (next, digit) in grid.neighbor_cells8(point) {
+                if digit.is_ascii_digit() {
+                    let (num, start) = get_whole_number(grid, next);
 
-                    if nx >= 0 && ny >= 0 && (ny as usize) < n && (nx as usize) < m {
-                        let (nx, ny) = (nx as usize, ny as usize);
-
-                        if input[ny][nx].is_ascii_digit() {
-                            let (num, start_x) = get_whole_number(input, nx, ny);
-
-                            if seen.insert((ny, start_x)) {
-                                // nums.push(num);
-                                adjacence.push(num);
-                            }
-                        }
+                    if seen.insert(start) {
+                        adjacence.push(num);
                     }
                 }
+            }
 
-                if adjacence.len() == 2 {
-                    nums.push(adjacence[0] * adjacence[1]);
-                }
+            if adjacence.len() == 2 {
+                nums.push(adjacence[0] * adjacence[1]);
             }
         }
     }
@@ -105,6 +83,24 @@ pub fn part_2(input: &[&[u8]]) -> usize {
     nums.iter().sum()
 }
 
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::year2023::day3::{parse, part_1, part_2};
@@ -133,11 +129,11 @@ mod test {
 
     #[test]
     fn test_part_1() {
-        assert_eq!(4361, part_1(&parse(SAMPLE_INPUT)));
+        assert_eq!(4361, part_1(&parse(SAMPLE_INPUT).unwrap()));
     }
 
-    // #[test]
-    // fn test_part_2() {
-    //     assert_eq!(4361, part_2(&parse(SAMPLE_INPUT_2)));
-    // }
+    #[test]
+    fn test_part_2() {
+        assert_eq!(467835, part_2(&parse(SAMPLE_INPUT_2).unwrap()));
+    }
 }
@@ -1,30 +1,33 @@
+use std::fmt::Display;
+
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::parse::{integers, key_value},
+};
+
 // Given that number are in range 0..99 we can avoid using a Hashet and using Static Bool Set
 type Set = [bool; 100];
 
-pub fn parse(input: &str) -> Vec<(Set, Set)> {
+pub fn parse(input: &str) -> Result<Vec<(Set, Set)>, ParseError> {
+    let to_set = |numbers: &str| -> Result<Set, ParseError> {
+        let mut mask = [false; 100];
+        for n in integers::<usize>(input, numbers) {
+            let idx = n?;
+            *mask
+                .get_mut(idx)
+                .ok_or_else(|| ParseError::at(input, numbers, "numbers below 100"))? = true;
+        }
+        Ok(mask)
+    };
+
     input
         .lines()
         .map(|line| {
-            let (winning_str, having_str) =
-                line.split_once(':').unwrap().1.split_once('|').unwrap();
-            let mut winning_mask = [false; 100];
-
-            for n in winning_str
-                .split_whitespace()
-                .map(|n| n.parse::<usize>().unwrap())
-            {
-                winning_mask[n] = true;
-            }
+            let (_, numbers) = key_value(input, line, ":")?;
+            let (winning_str, having_str) = key_value(input, numbers, "|")?;
 
-            let mut having_mask = [false; 100];
-            for n in having_str
-                .split_whitespace()
-                .map(|n| n.parse::<usize>().unwrap())
-            {
-                having_mask[n] = true;
-            }
-
-            (winning_mask, having_mask)
+            Ok((to_set(winning_str)?, to_set(having_str)?))
         })
         .collect()
 }
@@ -76,6 +79,24 @@ pub fn part_2(input: &[(Set, Set)]) -> u32 {
     counts.iter().sum()
 }
 
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<(Set, Set)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::year2023::day4::{parse, part_1};
@@ -87,8 +108,8 @@ Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
-    // #[test]
-    // fn test_part_1() {
-    //     assert_eq!(14, part_1(&parse(SAMPLE_INPUT)));
-    // }
+    #[test]
+    fn test_part_1() {
+        assert_eq!(13, part_1(&parse(SAMPLE_INPUT).unwrap()));
+    }
 }
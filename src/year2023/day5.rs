@@ -1,14 +1,33 @@
-pub fn part_1(input: &str) -> u32 {
-    let mut sections = input.split("\n\n");
-    let seeds: Vec<u32> = sections
-        .next()
-        .unwrap()
-        .split(":")
-        .last()
-        .unwrap()
-        .split_whitespace()
-        .map(|x| x.parse::<u32>().unwrap())
-        .collect();
+use std::fmt::Display;
+
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::parse::{integer_array, integers, sections},
+};
+
+pub struct Almanac {
+    seeds: Vec<u32>,
+    // One layer per map section, each rule is (destination_start, source_start, length)
+    maps: Vec<Vec<(u32, u32, u32)>>,
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let mut sections = sections(input);
+    let seeds_line = sections.next().unwrap_or_default();
+    let seeds_str = seeds_line
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::at(input, seeds_line, "`seeds:`"))?;
+    let seeds: Vec<u32> = integers(input, seeds_str).collect::<Result<_, _>>()?;
+
+    // Part 2 reads the seeds as (start, length) pairs
+    if !seeds.len().is_multiple_of(2) {
+        return Err(ParseError::at(
+            input,
+            &seeds_line[seeds_line.len()..],
+            "an even number of seeds",
+        ));
+    }
 
     let maps: Vec<Vec<(u32, u32, u32)>> = sections
         .map(|section| {
@@ -16,15 +35,19 @@ pub fn part_1(input: &str) -> u32 {
                 .lines()
                 .skip(1)
                 .map(|line| {
-                    let parts: Vec<u32> = line
-                        .split_whitespace()
-                        .map(|x| x.parse::<u32>().unwrap())
-                        .collect();
-                    (parts[0], parts[1], parts[2])
+                    let [destination, source, length] =
+                        integer_array(input, line, "`destination source length`")?;
+                    Ok((destination, source, length))
                 })
                 .collect()
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
+
+    Ok(Almanac { seeds, maps })
+}
+
+pub fn part_1(almanac: &Almanac) -> u32 {
+    let Almanac { seeds, maps } = almanac;
 
     seeds
         .iter()
@@ -42,34 +65,8 @@ pub fn part_1(input: &str) -> u32 {
         .unwrap_or(0)
 }
 
-pub fn part_2(input: &str) -> u32 {
-    let mut sections = input.split("\n\n");
-
-    let seeds: Vec<u32> = sections
-        .next()
-        .unwrap()
-        .split(":")
-        .last()
-        .unwrap()
-        .split_whitespace()
-        .map(|x| x.parse::<u32>().unwrap())
-        .collect();
-
-    let maps: Vec<Vec<(u32, u32, u32)>> = sections
-        .map(|section| {
-            section
-                .lines()
-                .skip(1)
-                .map(|line| {
-                    let parts: Vec<u32> = line
-                        .split_whitespace()
-                        .map(|x| x.parse::<u32>().unwrap())
-                        .collect();
-                    (parts[0], parts[1], parts[2])
-                })
-                .collect()
-        })
-        .collect();
+pub fn part_2(almanac: &Almanac) -> u32 {
+    let Almanac { seeds, maps } = almanac;
 
     let mut current_ranges: Vec<_> = seeds
         .chunks(2)
@@ -81,7 +78,7 @@ pub fn part_2(input: &str) -> u32 {
 
         let mut unprocessed = current_ranges;
 
-        for (dest_start, src_start, length) in map_layer {
+        for &(dest_start, src_start, length) in map_layer {
             let src_end = src_start + length;
             let mut next_unprocessed: Vec<(u32, u32)> = Vec::new();
 
@@ -135,6 +132,24 @@ pub fn part_2(input: &str) -> u32 {
         .unwrap()
 }
 
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::year2023::day5;
@@ -175,11 +190,11 @@ humidity-to-location map:
 
     #[test]
     fn part_1() {
-        assert_eq!(35, day5::part_1(SAMPLE_INPUT));
+        assert_eq!(35, day5::part_1(&day5::parse(SAMPLE_INPUT).unwrap()));
     }
 
     #[test]
     fn part_2() {
-        assert_eq!(46, day5::part_2(SAMPLE_INPUT));
+        assert_eq!(46, day5::part_2(&day5::parse(SAMPLE_INPUT).unwrap()));
     }
 }
@@ -7,20 +7,52 @@
 //! * `x * (t - x) = d`
 //! * `x² - tx +d = 0`
 
-pub fn part_1(input: &str) -> u64 {
-    let (time_line, dist_line) = input.split_once('\n').unwrap();
+use std::fmt::Display;
 
-    let times = time_line
-        .split_whitespace()
-        .filter_map(|s| s.parse::<u64>().ok());
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::parse::{integers, key_value},
+};
 
-    let distances = dist_line
-        .split_whitespace()
-        .filter_map(|s| s.parse::<u64>().ok());
+pub struct Races {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Result<Races, ParseError> {
+    let mut lines = input.lines();
+
+    let mut numbers = |label: &str| -> Result<Vec<u64>, ParseError> {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, format!("`{label}` line")))?;
+        let (key, numbers) = key_value(input, line, ":")?;
+        if key != label {
+            return Err(ParseError::at(input, key, format!("`{label}`")));
+        }
+        integers(input, numbers).collect()
+    };
+
+    let times = numbers("Time")?;
+    let distances = numbers("Distance")?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::end_of_input(
+            input,
+            "as many distances as times",
+        ));
+    }
 
-    times
-        .zip(distances)
-        .map(|(t, d)| different_ways(t, d))
+    Ok(Races { times, distances })
+}
+
+pub fn part_1(races: &Races) -> u64 {
+    races
+        .times
+        .iter()
+        .zip(&races.distances)
+        .map(|(&t, &d)| different_ways(t, d))
         .product()
 }
 
@@ -50,29 +82,35 @@ fn different_ways(t: u64, d: u64) -> u64 {
     (upper_bound - lower_bound) + 1
 }
 
-pub fn part_2(input: &str) -> u64 {
-    let mut lines = input.lines();
-    let time_string: String = lines
-        .next()
-        .unwrap()
-        .split(':')
-        .next_back()
-        .unwrap()
-        .split_whitespace()
-        .collect();
-
-    let time: u64 = time_string.parse().unwrap();
-
-    let distances: String = lines
-        .next()
-        .unwrap()
-        .split(':')
-        .next_back()
-        .unwrap()
-        .split_whitespace()
-        .collect();
-
-    let record_distance: u64 = distances.parse().unwrap();
+// Part 2 ignores the spaces between numbers, i.e. it concatenates their digits
+fn concat_digits(numbers: &[u64]) -> u64 {
+    numbers.iter().fold(0, |acc, &n| {
+        let digits = if n == 0 { 1 } else { n.ilog10() + 1 };
+        acc * 10_u64.pow(digits) + n
+    })
+}
+
+pub fn part_2(races: &Races) -> u64 {
+    let time = concat_digits(&races.times);
+    let record_distance = concat_digits(&races.distances);
 
     different_ways(time, record_distance)
 }
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Races;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
@@ -1,4 +1,14 @@
-use std::{cmp::Ordering, collections::HashMap, fmt::Debug};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
+
+use crate::{
+    error::{ParseError, parse_at},
+    solution::Solution,
+    util::parse::key_value,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum HandType {
@@ -157,21 +167,43 @@ fn get_index(card: u8, card_set: [u8; 13]) -> usize {
     unreachable!()
 }
 
-pub fn part_1(input: &str) -> u64 {
-    let mut hands: Vec<_> = input
+pub fn parse(input: &str) -> Result<Vec<([u8; 5], u64)>, ParseError> {
+    input
         .lines()
         .map(|l| {
-            let (hand, bid) = l.split_once(" ").unwrap();
-            let bid: u64 = bid.parse().unwrap();
+            let (hand, bid) = key_value(input, l, " ")?;
+            let bid: u64 = parse_at(input, bid, "bid")?;
+
+            if let Some(idx) = hand.bytes().position(|card| !CARDS.contains(&card)) {
+                return Err(ParseError::at(
+                    input,
+                    &hand[idx..],
+                    "card in `AKQJT98765432`",
+                ));
+            }
+            let hand = hand
+                .as_bytes()
+                .try_into()
+                .map_err(|_| ParseError::at(input, hand, "hand of 5 cards"))?;
+
+            Ok((hand, bid))
+        })
+        .collect()
+}
+
+pub fn part_1(input: &[([u8; 5], u64)]) -> u64 {
+    let mut hands: Vec<_> = input
+        .iter()
+        .map(|&(inner, bid)| {
             let hand = Cards {
-                inner: hand.as_bytes().try_into().unwrap(),
+                inner,
                 jokers: false,
             };
             Hand::new(hand, bid)
         })
         .collect();
 
-    hands.sort_unstable_by(|x, y| x.hand.cmp(&y.hand));
+    hands.sort_unstable_by_key(|h| h.hand);
 
     hands
         .iter()
@@ -180,14 +212,12 @@ pub fn part_1(input: &str) -> u64 {
         .sum()
 }
 
-pub fn part_2(input: &str) -> u64 {
+pub fn part_2(input: &[([u8; 5], u64)]) -> u64 {
     let mut hands: Vec<_> = input
-        .lines()
-        .map(|l| {
-            let (hand, bid) = l.split_once(" ").unwrap();
-            let bid: u64 = bid.parse().unwrap();
+        .iter()
+        .map(|&(inner, bid)| {
             let hand = Cards {
-                inner: hand.as_bytes().try_into().unwrap(),
+                inner,
                 jokers: true,
             };
 
@@ -195,7 +225,7 @@ pub fn part_2(input: &str) -> u64 {
         })
         .collect();
 
-    hands.sort_unstable_by(|x, y| x.hand.cmp(&y.hand));
+    hands.sort_unstable_by_key(|h| h.hand);
 
     hands
         .iter()
@@ -204,6 +234,24 @@ pub fn part_2(input: &str) -> u64 {
         .sum()
 }
 
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Vec<([u8; 5], u64)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::year2023::day7;
@@ -216,11 +264,11 @@ QQQJA 483";
 
     #[test]
     fn test_sample_input_parsing() {
-        assert_eq!(6440, day7::part_1(SAMPLE_INPUT));
+        assert_eq!(6440, day7::part_1(&day7::parse(SAMPLE_INPUT).unwrap()));
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!(5905, day7::part_2(SAMPLE_INPUT));
+        assert_eq!(5905, day7::part_2(&day7::parse(SAMPLE_INPUT).unwrap()));
     }
 }
@@ -1,23 +1,73 @@
+use std::fmt::Display;
+
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::FxHashMap as HashMap;
 
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::{
+        math::lcm,
+        parse::{delimited, key_value, split_sections},
+    },
+};
+
 const GOAL: &str = "ZZZ";
 
-pub fn part_2(input: &str) -> usize {
-    let (instructions, elements_str) = input.split_once("\n\n").unwrap();
+pub struct Network<'a> {
+    instructions: &'a str,
+    elements: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+pub fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+    let [instructions, elements_str] = split_sections(input)?;
+
+    if instructions.is_empty() {
+        return Err(ParseError::at(
+            input,
+            instructions,
+            "`L` or `R` instructions",
+        ));
+    }
+    if let Some(idx) = instructions.bytes().position(|b| b != b'L' && b != b'R') {
+        return Err(ParseError::at(input, &instructions[idx..], "`L` or `R`"));
+    }
+
     let mut elements: HashMap<&str, (&str, &str)> = HashMap::default();
-    // nodes that ends with A
-    let mut starting_nodes = vec![];
     for l in elements_str.lines() {
-        let key = &l[0..3];
+        let (key, targets) = key_value(input, l, "=")?;
+        let (_, targets, _) = delimited(input, targets, '(', ')')?;
+        let (left, right) = key_value(input, targets, ",")?;
+        elements.insert(key, (left, right));
+    }
 
-        if key.ends_with('A') {
-            starting_nodes.push(key);
+    for &(left, right) in elements.values() {
+        for target in [left, right] {
+            if !elements.contains_key(target) {
+                return Err(ParseError::at(input, target, "node defined in the network"));
+            }
         }
-
-        elements.insert(key, (&l[7..10], &l[12..15]));
     }
 
+    Ok(Network {
+        instructions,
+        elements,
+    })
+}
+
+pub fn part_2(network: &Network) -> usize {
+    let Network {
+        instructions,
+        elements,
+    } = network;
+
+    // nodes that ends with A
+    let starting_nodes: Vec<&str> = elements
+        .keys()
+        .copied()
+        .filter(|key| key.ends_with('A'))
+        .collect();
+
     starting_nodes
         .par_iter()
         .map(|&start| {
@@ -39,22 +89,11 @@ pub fn part_2(input: &str) -> usize {
         .reduce(|| 1, lcm)
 }
 
-fn gcd(a: usize, b: usize) -> usize {
-    if b == 0 { a } else { gcd(b, a % b) }
-}
-
-fn lcm(a: usize, b: usize) -> usize {
-    a * b / gcd(a, b)
-}
-
-pub fn part_1(input: &str) -> usize {
-    let (instructions, elements_str) = input.split_once("\n\n").unwrap();
-    let mut elements: HashMap<&str, (&str, &str)> = HashMap::default();
-    for l in elements_str.lines() {
-        let key = &l[0..3];
-        let val = (&l[7..10], &l[12..15]);
-        elements.insert(key, val);
-    }
+pub fn part_1(network: &Network) -> usize {
+    let Network {
+        instructions,
+        elements,
+    } = network;
 
     let mut next_node = "AAA";
     let mut steps = 0;
@@ -70,3 +109,21 @@ pub fn part_1(input: &str) -> usize {
 
     steps
 }
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Network<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
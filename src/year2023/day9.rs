@@ -1,10 +1,15 @@
-pub fn parse(input: &str) -> Vec<Vec<i64>> {
+use std::fmt::Display;
+
+use crate::{error::ParseError, solution::Solution, util::parse::integers};
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
     input
         .lines()
         .map(|l| {
-            l.split_whitespace()
-                .map(|n| n.parse::<i64>().unwrap())
-                .collect()
+            if l.trim().is_empty() {
+                return Err(ParseError::at(input, l, "history values"));
+            }
+            integers(input, l).collect()
         })
         .collect()
 }
@@ -50,3 +55,21 @@ fn extrapolate_backward(history: &[i64]) -> i64 {
 pub fn part_2(oasis_report: &[Vec<i64>]) -> i64 {
     oasis_report.iter().map(|v| extrapolate_backward(v)).sum()
 }
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
@@ -1,19 +1,39 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{ParseError, parse_at},
+    solution::Solution,
+};
+
 const DIAL_START: i32 = 50;
 const N: i32 = 100;
 
-pub fn part_1(input: &str) -> usize {
+/// Rotations as signed clicks, `L` turns are negative.
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (sign, number) = if let Some(number) = line.strip_prefix('L') {
+                (-1, number)
+            } else if let Some(number) = line.strip_prefix('R') {
+                (1, number)
+            } else {
+                return Err(ParseError::at(input, line, "`L` or `R`"));
+            };
+            let number = parse_at::<u32>(input, number, "number of clicks")?;
+            let number = i32::try_from(number)
+                .map_err(|_| ParseError::at(input, &line[1..], "smaller number of clicks"))?;
+            Ok(sign * number)
+        })
+        .collect()
+}
+
+pub fn part_1(rotations: &[i32]) -> usize {
     // 0..99
     let mut dial: i32 = DIAL_START;
     let mut count = 0;
-    for line in input.lines() {
-        let (rotation, number) = line.split_at(1);
-        let number = number.parse::<i32>().unwrap();
-        dial = if rotation == "L" {
-            dial - number
-        } else {
-            dial + number
-        }
-        .rem_euclid(N);
+    for &rotation in rotations {
+        dial = (dial + rotation).rem_euclid(N);
 
         count += usize::from(dial == 0);
     }
@@ -21,16 +41,14 @@ pub fn part_1(input: &str) -> usize {
     count
 }
 
-pub fn part_2(input: &str) -> u32 {
+pub fn part_2(rotations: &[i32]) -> u32 {
     let mut dial: i32 = DIAL_START;
     let mut count = 0;
 
-    for line in input.lines() {
-        let (rotation, number) = line.split_at(1);
-
-        let number = number.parse::<i32>().unwrap();
+    for &rotation in rotations {
+        let number = rotation.abs();
 
-        if rotation == "R" {
+        if rotation > 0 {
             count += (dial + number).div_euclid(N);
             dial = (dial + number).rem_euclid(N)
         } else {
@@ -60,9 +78,28 @@ pub fn part_2(input: &str) -> u32 {
     count as u32
 }
 
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::year2025;
+    use super::{DIAL_START, N};
+    use crate::{testing::cross_check, util::rng::Rng, year2025};
 
     const SAMPLE_INPUT: &str = "L68
 L30
@@ -77,16 +114,56 @@ L82";
 
     #[test]
     fn test_1() {
-        assert_eq!(3, year2025::day01::part_1(SAMPLE_INPUT));
+        let input = year2025::day01::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(3, year2025::day01::part_1(&input));
     }
 
     #[test]
     fn test_2() {
-        assert_eq!(6, year2025::day01::part_2(SAMPLE_INPUT));
+        let input = year2025::day01::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(6, year2025::day01::part_2(&input));
     }
 
     #[test]
     fn assert_r100_edge_case() {
-        assert_eq!(10, year2025::day01::part_2("R1000"))
+        let input = year2025::day01::parse("R1000").unwrap();
+        assert_eq!(10, year2025::day01::part_2(&input))
+    }
+
+    /// Turns the dial one click at a time.
+    fn part_2_naive(rotations: &[i32]) -> u32 {
+        let mut dial = DIAL_START;
+        let mut count = 0;
+        for &rotation in rotations {
+            for _ in 0..rotation.abs() {
+                dial = (dial + rotation.signum()).rem_euclid(N);
+                count += u32::from(dial == 0);
+            }
+        }
+        count
+    }
+
+    /// Rotations biased towards whole turns, where the closed form has its edge cases.
+    fn rotations(rng: &mut Rng) -> Vec<i32> {
+        (0..rng.range(1..=20))
+            .map(|_| {
+                let clicks = if rng.chance(1, 3) {
+                    100 * rng.range(0..=10) + rng.range(-1..=1)
+                } else {
+                    rng.range(0..=1000)
+                };
+                let sign = if rng.chance(1, 2) { -1 } else { 1 };
+                sign * clicks.max(0) as i32
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_part_2_matches_naive() {
+        cross_check(
+            rotations,
+            |rotations| year2025::day01::part_2(rotations),
+            |rotations| part_2_naive(rotations),
+        );
     }
 }
@@ -1,11 +1,20 @@
+use std::fmt::Display;
+
 use rustc_hash::FxHashSet as HashSet;
 
-pub fn parse(input: &str) -> Vec<(u64, u64)> {
+use crate::{error::ParseError, solution::Solution, util::parse::integer_array};
+
+pub fn parse(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
     input
         .split(',')
-        .filter_map(|s| {
-            let (a, b) = s.trim().split_once('-')?;
-            Some((a.parse().ok()?, b.parse().ok()?))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let [start, end] = integer_array::<u64, 2>(input, s, "range `start-end`")?;
+            if start == 0 || end < start {
+                return Err(ParseError::at(input, s, "non-empty range of positive ids"));
+            }
+            Ok((start, end))
         })
         .collect()
 }
@@ -90,20 +99,80 @@ fn generate_sequence_part_2(total_digits: u32) -> impl Iterator<Item = u64> {
     })
 }
 
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::year2025::{self, day02::parse};
+    use super::{get_invalid_ids, get_invalid_ids_part_2};
+    use crate::{
+        testing::cross_check,
+        util::rng::Rng,
+        year2025::{self, day02::parse},
+    };
 
     const TEST_INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
     #[test]
     fn test_part_1() {
-        let input = parse(TEST_INPUT);
+        let input = parse(TEST_INPUT).unwrap();
         assert_eq!(1227775554, year2025::day02::part_1(&input))
     }
 
     #[test]
     fn test_part_2() {
-        let input = parse(TEST_INPUT);
+        let input = parse(TEST_INPUT).unwrap();
         assert_eq!(4174379265, year2025::day02::part_2(&input))
     }
+
+    /// Sums the ids of `range` made of a digit sequence repeated, exactly twice when `twice`.
+    fn invalid_ids_naive((start, end): (u64, u64), twice: bool) -> u64 {
+        (start..=end)
+            .filter(|id| {
+                let digits = id.to_string().into_bytes();
+                let len = digits.len();
+                (1..len)
+                    .filter(|pattern| len % pattern == 0 && (!twice || len == 2 * pattern))
+                    .any(|pattern| digits.chunks(pattern).all(|c| c == &digits[..pattern]))
+            })
+            .sum()
+    }
+
+    fn id_range(rng: &mut Rng) -> (u64, u64) {
+        let digits = rng.range(1..=8) as u32;
+        let start = rng.range(1..=10_i64.pow(digits)) as u64;
+        (start, start + rng.below(2000))
+    }
+
+    #[test]
+    fn test_part_1_matches_naive() {
+        cross_check(
+            id_range,
+            |&range| get_invalid_ids(range),
+            |&range| invalid_ids_naive(range, true),
+        );
+    }
+
+    #[test]
+    fn test_part_2_matches_naive() {
+        cross_check(
+            id_range,
+            |&range| get_invalid_ids_part_2(range),
+            |&range| invalid_ids_naive(range, false),
+        );
+    }
 }
@@ -1,25 +1,32 @@
-pub fn parse(input: &str) -> Vec<&[u8]> {
-    input.lines().map(|l| l.as_bytes()).collect()
+use std::fmt::Display;
+
+use crate::{error::ParseError, solution::Solution};
+
+pub fn parse(input: &str) -> Result<Vec<&[u8]>, ParseError> {
+    input
+        .lines()
+        .map(|l| {
+            if let Some(idx) = l.bytes().position(|b| !b.is_ascii_digit()) {
+                return Err(ParseError::at(input, &l[idx..], "joltage digit"));
+            }
+            // Part 2 turns on 12 batteries of every bank
+            if l.len() < 12 {
+                return Err(ParseError::at(
+                    input,
+                    &l[l.len()..],
+                    "at least 12 batteries",
+                ));
+            }
+            Ok(l.as_bytes())
+        })
+        .collect()
 }
 
 pub fn part_1(input: &[&[u8]]) -> u64 {
     input
         .iter()
         .map(|&bank| {
-            // // O(N^2 ) solution``
-            // Day 3 - Part 1 solution 17535 - elapsed 608.222µs
-            // let mut max_joltage: u64 = 0;
-            // let len = bank.len();
-            // for i in 0..len {
-            //     let base = (bank[i] - b'0') * 10;
-            //     for b in bank.iter().take(len).skip(i + 1) {
-            //         let curr_joltage = base + b - b'0';
-            //         max_joltage = max_joltage.max(u64::from(curr_joltage))
-            //     }
-            // }
-            //
-            // max_joltage
-            // Day 3 - Part 1 solution 17535 - elapsed 72.34µs
+            // Replaced an O(N^2) pair search (608µs -> 72µs), kept as the reference in the tests
             max_joltage(bank, 2)
         })
         .sum()
@@ -78,9 +85,28 @@ fn left_most_max(sub_bank: &[u8]) -> (u8, usize) {
     left_most_max
 }
 
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<&'a [u8]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{testing::cross_check, util::rng::Rng};
 
     const TEST_INPUT: &str = "987654321111111
 811111111111119
@@ -88,13 +114,65 @@ mod test {
 818181911112111";
     #[test]
     fn test_part_1() {
-        let input = parse(TEST_INPUT);
+        let input = parse(TEST_INPUT).unwrap();
         assert_eq!(357, part_1(&input))
     }
 
     #[test]
     fn test_part_2() {
-        let input = parse(TEST_INPUT);
+        let input = parse(TEST_INPUT).unwrap();
         assert_eq!(3121910778619, part_2(&input))
     }
+
+    /// The original O(N^2) search over every pair of batteries.
+    fn max_pair_naive(bank: &[u8]) -> u64 {
+        let mut max_joltage: u64 = 0;
+        let len = bank.len();
+        for i in 0..len {
+            let base = (bank[i] - b'0') * 10;
+            for b in bank.iter().take(len).skip(i + 1) {
+                let curr_joltage = base + b - b'0';
+                max_joltage = max_joltage.max(u64::from(curr_joltage))
+            }
+        }
+        max_joltage
+    }
+
+    /// Tries every subset of `n_digits` batteries.
+    fn max_joltage_naive(bank: &[u8], n_digits: usize) -> Option<u64> {
+        if n_digits == 0 {
+            return Some(0);
+        }
+        let (&first, rest) = bank.split_first()?;
+        let take = max_joltage_naive(rest, n_digits - 1)
+            .map(|tail| u64::from(first - b'0') * 10_u64.pow(n_digits as u32 - 1) + tail);
+        take.max(max_joltage_naive(rest, n_digits))
+    }
+
+    fn bank(rng: &mut Rng) -> Vec<u8> {
+        (0..rng.range(12..=14))
+            .map(|_| b'0' + rng.below(10) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn test_max_pair_matches_naive() {
+        cross_check(
+            bank,
+            |bank| max_joltage(bank, 2),
+            |bank| max_pair_naive(bank),
+        );
+    }
+
+    #[test]
+    fn test_max_joltage_matches_naive() {
+        cross_check(
+            |rng| {
+                let n_digits = rng.range(1..=12) as usize;
+                (bank(rng), n_digits)
+            },
+            |(bank, n_digits)| max_joltage(bank, *n_digits),
+            |(bank, n_digits)| max_joltage_naive(bank, *n_digits).unwrap(),
+        );
+    }
 }
@@ -1,21 +1,25 @@
-use crate::util::{
-    grid::Grid,
-    point::{DIRECTIONS, Point},
+use std::fmt::Display;
+
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::{grid::Grid, point::Point},
 };
 
-pub fn parse(input: &str) -> Grid<u8> {
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    if input.trim().is_empty() {
+        return Err(ParseError::end_of_input(input, "grid of paper rolls"));
+    }
     Grid::parse(input)
 }
 
 pub fn part_1(grid: &Grid<u8>) -> u32 {
     find_all_paper_rolls(grid)
         .filter(|&point| {
-            let mut count = 0;
-            for d in DIRECTIONS {
-                let new_dir = point + d;
-                count += grid.get(new_dir).map_or(0, |e| u32::from(*e == b'@'))
-            }
-            count < 4
+            grid.neighbor_cells8(point)
+                .filter(|&(_, &e)| e == PAPER_ROLL)
+                .count()
+                < 4
         })
         .count() as u32
 }
@@ -23,21 +27,14 @@ pub fn part_1(grid: &Grid<u8>) -> u32 {
 const PAPER_ROLL: u8 = b'@';
 
 fn find_all_paper_rolls(grid: &Grid<u8>) -> impl Iterator<Item = Point> {
-    grid.data.iter().enumerate().filter_map(|(idx, e)| {
-        if *e == PAPER_ROLL {
-            // Calculate point for a match
-            let x = (idx as i32) % grid.width;
-            let y = (idx as i32) / grid.width;
-            Some(Point::new(x, y)) // Return Some(Point)
-        } else {
-            None // Return None to discard non-matches
-        }
-    })
+    grid.cells()
+        .filter(|&(_, &e)| e == PAPER_ROLL)
+        .map(|(point, _)| point)
 }
 
-pub fn part_2(grid: Grid<u8>) -> u64 {
+pub fn part_2(grid: &Grid<u8>) -> u64 {
     // let paper_rolls_points =
-    let mut grid = grid;
+    let mut grid = grid.clone();
     let mut total_removed = 0;
     let mut curr_removed = 1;
 
@@ -49,9 +46,9 @@ pub fn part_2(grid: Grid<u8>) -> u64 {
                 if grid[point] != b'@' {
                     return false;
                 }
-                let count = DIRECTIONS
-                    .iter()
-                    .filter(|&&d| grid.get(point + d).is_some_and(|&e| e == b'@'))
+                let count = grid
+                    .neighbor_cells8(point)
+                    .filter(|&(_, &e)| e == PAPER_ROLL)
                     .count();
                 count < 4
             })
@@ -67,6 +64,24 @@ pub fn part_2(grid: Grid<u8>) -> u64 {
     total_removed
 }
 
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -84,13 +99,13 @@ mod test {
 
     #[test]
     fn test_part_1() {
-        let input = parse(TEST_INPUT);
+        let input = parse(TEST_INPUT).unwrap();
         assert_eq!(13, part_1(&input))
     }
 
     #[test]
     fn test_part_2() {
-        let input = parse(TEST_INPUT);
-        assert_eq!(43, part_2(input))
+        let input = parse(TEST_INPUT).unwrap();
+        assert_eq!(43, part_2(&input))
     }
 }
@@ -1,19 +1,35 @@
-use std::ops::RangeInclusive;
+use std::fmt::Display;
+
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::parse::{integer_array, integers, split_sections},
+};
 
 pub struct Input {
     ranges: Vec<(u64, u64)>,
     ids: Vec<u64>,
 }
-pub fn parse(input: &str) -> Input {
-    let (ranges, ids) = input.split_once("\n\n").unwrap();
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let [ranges, ids] = split_sections(input)?;
     let mut ranges = ranges
         .lines()
         .map(|range_l| {
-            let (start, end) = range_l.split_once('-').unwrap();
-            let (start, end): (u64, u64) = (start.parse().unwrap(), end.parse().unwrap());
-            (start, end)
+            let [start, end] = integer_array::<u64, 2>(input, range_l, "range `start-end`")?;
+            if end < start {
+                return Err(ParseError::at(input, range_l, "range with start <= end"));
+            }
+            // Merging looks one past the end of each range
+            if end == u64::MAX {
+                return Err(ParseError::at(input, range_l, "range end below u64::MAX"));
+            }
+            Ok((start, end))
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if ranges.is_empty() {
+        return Err(ParseError::at(input, input, "fresh id range"));
+    }
 
     ranges.sort_unstable();
 
@@ -35,12 +51,9 @@ pub fn parse(input: &str) -> Input {
     merged_ranges.push(curr_merged);
     let ranges = merged_ranges;
 
-    let ids = ids
-        .lines()
-        .map(|id| id.parse().unwrap())
-        .collect::<Vec<_>>();
+    let ids = integers(input, ids).collect::<Result<Vec<_>, _>>()?;
 
-    Input { ranges, ids }
+    Ok(Input { ranges, ids })
 }
 pub fn part_1(input: &Input) -> usize {
     input
@@ -63,3 +76,21 @@ pub fn part_2(input: &Input) -> u64 {
         .map(|&(start, end)| end - start + 1)
         .sum()
 }
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
@@ -1,4 +1,6 @@
-use std::{ops::Range, vec};
+use std::{fmt::Display, ops::Range, vec};
+
+use crate::{error::ParseError, solution::Solution, util::parse::integers};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum Ops {
@@ -6,34 +8,68 @@ enum Ops {
     Mul,
 }
 
-pub struct Input {
+pub struct Input<'a> {
     ops: Vec<Ops>,
+    // Column of each operator, a problem spans from its operator up to the next one
+    ops_idx: Vec<usize>,
     inputs: Vec<Vec<u64>>,
+    // Number rows bottom to top, kept raw because part 2 reads them column-wise
+    lines: Vec<&'a str>,
 }
 
-pub fn parse(input: &str) -> Input {
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
     let mut lines = input.lines().rev();
 
-    let ops: Vec<Ops> = lines
+    let mut ops = vec![];
+    let mut ops_idx = vec![];
+
+    let ops_line = lines
         .next()
-        .unwrap()
-        .chars()
-        .filter(|c| !c.is_whitespace())
-        .map(|c| if c == '*' { Ops::Mul } else { Ops::Add })
-        .collect();
+        .ok_or_else(|| ParseError::end_of_input(input, "line of operators"))?;
+
+    for (idx, op) in ops_line
+        .char_indices()
+        .filter(|(_idx, char)| !char.is_whitespace())
+    {
+        match op {
+            '*' => ops.push(Ops::Mul),
+            '+' => ops.push(Ops::Add),
+            _ => return Err(ParseError::at(input, &ops_line[idx..], "`*` or `+`")),
+        }
+
+        ops_idx.push(idx);
+    }
+
+    if ops.is_empty() {
+        return Err(ParseError::at(input, ops_line, "`*` or `+`"));
+    }
+
+    let lines: Vec<_> = lines.collect();
 
     let inputs: Vec<Vec<u64>> = lines
+        .iter()
         .map(|l| {
-            l.split_whitespace()
-                .map(|c| c.parse::<u64>().unwrap())
-                .collect()
+            let row = integers(input, l).collect::<Result<Vec<u64>, _>>()?;
+            if row.len() != ops.len() {
+                return Err(ParseError::at(input, l, "one number per operator"));
+            }
+            Ok(row)
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    Input { ops, inputs }
+    if inputs.is_empty() {
+        return Err(ParseError::at(input, input, "rows of numbers"));
+    }
+
+    Ok(Input {
+        ops,
+        ops_idx,
+        inputs,
+        lines,
+    })
 }
 
-pub fn part_1(input: &Input) -> u64 {
+pub fn part_1(input: &Input<'_>) -> u64 {
     input.inputs[0]
         .iter()
         .enumerate()
@@ -48,29 +84,13 @@ pub fn part_1(input: &Input) -> u64 {
         .sum()
 }
 
-pub fn part_2(input: &str) -> u64 {
-    let mut lines = input.lines().rev();
-
-    let mut ops = vec![];
-    let mut ops_idx = vec![];
-
-    for (idx, op) in lines
-        .next()
-        .unwrap()
-        .chars()
-        .enumerate()
-        .filter(|(_idx, char)| !char.is_whitespace())
-    {
-        if op == '*' {
-            ops.push(Ops::Mul);
-        } else {
-            ops.push(Ops::Add);
-        }
-
-        ops_idx.push(idx);
-    }
-
-    let lines: Vec<_> = lines.collect();
+pub fn part_2(input: &Input<'_>) -> u64 {
+    let Input {
+        ops,
+        ops_idx,
+        lines,
+        ..
+    } = input;
     let line_len = lines[0].len();
 
     let mut range_vec: Vec<Range<usize>> = Vec::new();
@@ -118,6 +138,24 @@ pub fn part_2(input: &str) -> u64 {
     count
 }
 
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -128,12 +166,13 @@ mod test {
 *   +   *   +  ";
     #[test]
     fn test_part_1() {
-        let input = parse(SAMPLE_INPUT);
+        let input = parse(SAMPLE_INPUT).unwrap();
         assert_eq!(4277556, part_1(&input))
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!(3263827, part_2(SAMPLE_INPUT))
+        let input = parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(3263827, part_2(&input))
     }
 }
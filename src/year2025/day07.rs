@@ -1,14 +1,25 @@
+use std::fmt::Display;
+
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-use crate::util::{
-    grid::Grid,
-    point::{Point, SOUTH, SOUTH_EAST, SOUTH_WEST},
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::{
+        grid::Grid,
+        point::{Point, SOUTH, SOUTH_EAST, SOUTH_WEST},
+    },
 };
 
-pub fn parse(input: &str) -> (Grid<u8>, Point) {
-    let grid = Grid::parse(input);
-    let starting_point = grid.find(b'S').unwrap();
-    (grid, starting_point)
+pub fn parse(input: &str) -> Result<(Grid<u8>, Point), ParseError> {
+    if !input.contains('S') {
+        return Err(ParseError::end_of_input(input, "starting point `S`"));
+    }
+    let grid = Grid::parse(input)?;
+    let starting_point = grid
+        .find(b'S')
+        .ok_or_else(|| ParseError::end_of_input(input, "starting point `S` inside the grid"))?;
+    Ok((grid, starting_point))
 }
 
 pub fn part_1((grid, starting_point): &(Grid<u8>, Point)) -> u64 {
@@ -76,6 +87,24 @@ pub fn part_2((grid, starting_point): &(Grid<u8>, Point)) -> u64 {
     solve(*starting_point, grid, &mut memo)
 }
 
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = (Grid<u8>, Point);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     const SAMPLE_INPUT: &str = ".......S.......
@@ -97,13 +126,13 @@ mod test {
     use super::*;
     #[test]
     fn test_part_1() {
-        let input = parse(SAMPLE_INPUT);
+        let input = parse(SAMPLE_INPUT).unwrap();
         assert_eq!(21, part_1(&input));
     }
 
     #[test]
     fn test_part_2() {
-        let input = parse(SAMPLE_INPUT);
+        let input = parse(SAMPLE_INPUT).unwrap();
         assert_eq!(40, part_2(&input));
     }
 }
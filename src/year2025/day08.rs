@@ -1,15 +1,13 @@
-use std::collections::{BinaryHeap, HashSet};
+use std::fmt::Display;
 
-pub fn parse(input: &str) -> Vec<Vec3> {
+use crate::{error::ParseError, solution::Solution, util::parse::integer_array};
+
+pub fn parse(input: &str) -> Result<Vec<Vec3>, ParseError> {
     input
         .lines()
         .map(|l| {
-            let mut coords = l.split(",").map(|str| str.parse().unwrap());
-
-            let x = coords.next().unwrap();
-            let y = coords.next().unwrap();
-            let z = coords.next().unwrap();
-            Vec3(x, y, z)
+            let [x, y, z] = integer_array(input, l, "`x,y,z`")?;
+            Ok(Vec3(x, y, z))
         })
         .collect()
 }
@@ -37,7 +35,7 @@ impl Vec3 {
 pub fn part_1(coords: &[Vec3]) -> u64 {
     let distances: Vec<_> = coords
         .iter()
-        .map(|&p| {
+        .map(|&_p| {
             // let heap_distance: BinaryHeap<(i64, Vec3)> = coords
             //     .iter()
             //     .filter(|b| **b != p)
@@ -58,6 +56,26 @@ pub fn part_1(coords: &[Vec3]) -> u64 {
     todo!();
 }
 
-pub fn part_2(coords: &[Vec3]) -> u64 {
+pub fn part_2(_coords: &[Vec3]) -> u64 {
     todo!()
 }
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<Vec3>;
+
+    const IMPLEMENTED: [bool; 2] = [false, false];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
@@ -1,21 +1,33 @@
-use std::collections::VecDeque;
-
-use crate::util::{
-    grid::Grid,
-    point::{DIRECTIONS, NORTH, Point, WEST},
+use std::fmt::Display;
+
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::{
+        compress::Compression, grid::Grid, parse::integer_array, point::Point,
+        prefix_sum::PrefixSum2D, search::grid_bfs,
+    },
 };
 use itertools::Itertools;
-use rayon::slice::ParallelSliceMut;
-use rustc_hash::FxHashMap as HashMap;
 
-pub fn parse(input: &str) -> Vec<Point> {
-    input
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    // Part 2 adds a border one unit around the polygon, which must still fit in i32
+    let coords = input
         .lines()
         .map(|l| {
-            let (x, y) = l.split_once(",").unwrap();
-            Point::new(x.parse().unwrap(), y.parse().unwrap())
+            let [x, y] = integer_array::<i32, 2>(input, l, "`x,y`")?;
+            if ![x, y].iter().all(|c| (0..i32::MAX).contains(c)) {
+                return Err(ParseError::at(input, l, "non-negative coordinates"));
+            }
+            Ok(Point::new(x, y))
         })
-        .collect()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if coords.len() < 2 {
+        return Err(ParseError::end_of_input(input, "at least two red tiles"));
+    }
+
+    Ok(coords)
 }
 
 pub fn part_1(coords: &[Point]) -> u64 {
@@ -28,36 +40,15 @@ pub fn part_1(coords: &[Point]) -> u64 {
 }
 
 pub fn part_2(coords: &[Point]) -> u64 {
-    let mut x_vals: Vec<i32> = coords.iter().map(|p| p.x).collect();
-    let mut y_vals: Vec<i32> = coords.iter().map(|p| p.y).collect();
-
-    x_vals.push(i32::MIN);
-    x_vals.push(i32::MAX);
-    y_vals.push(i32::MIN);
-    y_vals.push(i32::MAX);
-
-    x_vals.sort_unstable();
-    x_vals.dedup();
-    y_vals.sort_unstable();
-    y_vals.dedup();
-
-    let x_map: HashMap<i32, i32> = x_vals
-        .iter()
-        .enumerate()
-        .map(|(i, &v)| (v, i as i32))
-        .collect();
-    let y_map: HashMap<i32, i32> = y_vals
-        .iter()
-        .enumerate()
-        .map(|(i, &v)| (v, i as i32))
-        .collect();
-
+    // The border keeps the outside of the polygon connected around it
+    let compression = Compression::with_border(coords.iter().copied());
     let shrunk: Vec<Point> = coords
         .iter()
-        .map(|p| Point::new(x_map[&p.x], y_map[&p.y]))
+        .map(|&p| compression.compress(p).unwrap())
         .collect();
 
-    let mut grid = Grid::new(x_vals.len() as i32, y_vals.len() as i32, 2i64);
+    let (width, height) = compression.size();
+    let mut grid = Grid::new(width, height, 2u8);
 
     for i in 0..coords.len() {
         let p1 = shrunk[i];
@@ -76,50 +67,27 @@ pub fn part_2(coords: &[Point]) -> u64 {
         }
     }
 
-    // Flood fill from origin (guaranteed exterior due to sentinels)
-    let mut queue = VecDeque::new();
-    queue.push_back(Point::new(0, 0));
-    grid[Point::new(0, 0)] = 0; // OUTSIDE
-
-    while let Some(point) = queue.pop_front() {
-        for dir in &DIRECTIONS[0..4] {
-            let next = point + *dir;
-            if let Some(val) = grid.get_mut(next)
-                && *val == 2
-            {
-                *val = 0;
-                queue.push_back(next);
-            }
-        }
+    // Flood fill from origin (guaranteed exterior due to the border)
+    let outside = grid_bfs(
+        &grid,
+        [Point::new(0, 0)],
+        |point| grid.neighbors4(point).filter(|&next| grid[next] == 2),
+        |_| false,
+    );
+    for idx in outside.visited.iter_ones() {
+        grid.data[idx] = 0; // OUTSIDE
     }
 
-    // Build prefix sum: grid[x,y] = count of valid cells in rectangle from (0,0) to (x,y)
-    for y in 1..grid.height {
-        for x in 1..grid.width {
-            let p = Point::new(x, y);
-            let valid = i64::from(grid[p] != 0);
-            grid[p] = valid + grid[p + NORTH] + grid[p + WEST] - grid[p + NORTH + WEST];
-        }
-    }
+    let valid = PrefixSum2D::count(&grid, |&cell| cell != 0);
 
     let mut max_area = 0u64;
 
     for i in 0..coords.len() {
         for j in (i + 1)..coords.len() {
-            let (x1, y1, x2, y2) = (
-                shrunk[i].x.min(shrunk[j].x),
-                shrunk[i].y.min(shrunk[j].y),
-                shrunk[i].x.max(shrunk[j].x),
-                shrunk[i].y.max(shrunk[j].y),
-            );
-
-            let expected = ((x2 - x1 + 1) * (y2 - y1 + 1)) as i64;
-            let actual = grid[Point::new(x2, y2)]
-                - grid[Point::new(x1 - 1, y2)]
-                - grid[Point::new(x2, y1 - 1)]
-                + grid[Point::new(x1 - 1, y1 - 1)];
-
-            if expected == actual {
+            let (a, b) = (shrunk[i], shrunk[j]);
+            let cells = i64::from(a.x.abs_diff(b.x) + 1) * i64::from(a.y.abs_diff(b.y) + 1);
+
+            if valid.sum(a, b) == cells {
                 let area = area(&coords[i], &coords[j]);
                 max_area = max_area.max(area);
             }
@@ -129,14 +97,6 @@ pub fn part_2(coords: &[Point]) -> u64 {
     max_area
 }
 
-#[repr(i64)]
-enum CellType {
-    Unknown = 0i64,
-    Boundary = 1i64,
-    Interior = 2i64,
-    Exterior = 3i64,
-}
-
 #[inline]
 pub fn area(p1: &Point, p2: &Point) -> u64 {
     let x_distance = p1.x.abs_diff(p2.x) as u64;
@@ -145,6 +105,24 @@ pub fn area(p1: &Point, p2: &Point) -> u64 {
     (x_distance + 1) * (y_distance + 1)
 }
 
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -164,6 +142,13 @@ mod test {
 
     #[test]
     fn test_part_2() {
-        assert_eq!(24, part_2(&parse(SAMPLE_INPUT)))
+        assert_eq!(24, part_2(&parse(SAMPLE_INPUT).unwrap()))
+    }
+
+    #[test]
+    fn test_part_2_outside_between_arms() {
+        // The outside between the arms has no coordinate of its own
+        let input = "0,0\n2,0\n2,5\n6,5\n6,0\n8,0\n8,7\n0,7";
+        assert_eq!(24, part_2(&parse(input).unwrap()))
     }
 }
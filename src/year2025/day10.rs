@@ -1,10 +1,16 @@
-use std::{collections::VecDeque, ops::BitXor};
+use std::{collections::VecDeque, fmt::Display, ops::BitXor};
 
 use bitvec::prelude::*;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use z3::ast::Int;
 use z3::{Optimize, SatResult};
 
+use crate::{
+    error::{ParseError, parse_at},
+    solution::Solution,
+    util::parse::{blocks, delimited, integers},
+};
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 struct Indicator {
     bytes: u16,
@@ -19,25 +25,42 @@ impl BitXor for Indicator {
     }
 }
 
+// An indicator packs one light per bit
+const MAX_LIGHTS: usize = u16::BITS as usize;
+
 impl Indicator {
-    pub fn parse(input: &str) -> Indicator {
+    /// Parses a light diagram such as `.##.`, `diagram` must be a subslice of `input`.
+    pub fn parse(input: &str, diagram: &str) -> Result<Indicator, ParseError> {
+        if diagram.len() > MAX_LIGHTS {
+            return Err(ParseError::at(input, diagram, "at most 16 lights"));
+        }
+
         let mut bits = 0u16;
-        for (i, c) in input.chars().enumerate() {
-            if c == '#' {
-                bits |= 1 << i;
+        for (i, c) in diagram.char_indices() {
+            match c {
+                '#' => bits |= 1 << i,
+                '.' => {}
+                _ => return Err(ParseError::at(input, &diagram[i..], "`.` or `#`")),
             }
         }
-        Indicator { bytes: bits }
+        Ok(Indicator { bytes: bits })
     }
 
-    pub fn parse_bit_set(input: &str) -> Indicator {
+    /// Parses the lights toggled by a button such as `1,3`.
+    pub fn parse_bit_set(input: &str, wiring: &str) -> Result<Indicator, ParseError> {
         let mut bits: u16 = 0;
-        for index_str in input.split(',') {
-            if let Ok(index) = index_str.trim().parse::<u16>() {
-                bits |= 1 << index;
+        for index_str in wiring.split(',') {
+            let index = parse_at::<usize>(input, index_str.trim(), "light index")?;
+            if index >= MAX_LIGHTS {
+                return Err(ParseError::at(
+                    input,
+                    index_str.trim(),
+                    "light index below 16",
+                ));
             }
+            bits |= 1 << index;
         }
-        Indicator { bytes: bits }
+        Ok(Indicator { bytes: bits })
     }
 
     pub fn is_set(&self, index: u8) -> bool {
@@ -47,35 +70,31 @@ impl Indicator {
 }
 
 // The manual describes one machine per line. Each line contains a single indicator light diagram in [square brackets], one or more button wiring schematics in (parentheses), and joltage requirements in {curly braces}.
-fn line_parser(line: &str) -> Input {
-    let bracket_start = line.find("[").unwrap() + 1;
-    let bracket_end = line.find("]").unwrap();
+fn line_parser(input: &str, line: &str) -> Result<Input, ParseError> {
+    let (_, diagram, rest) = delimited(input, line, '[', ']')?;
+    let (buttons, joltage_str, _) = delimited(input, rest, '{', '}')?;
 
-    let indicator = Indicator::parse(&line[bracket_start..bracket_end]);
+    let indicator = Indicator::parse(input, diagram)?;
 
-    let curly_start = line.find('{').unwrap();
-    let curly_end = line.find('}').unwrap();
+    let joltage: Vec<u32> = integers(input, joltage_str).collect::<Result<_, _>>()?;
 
-    let joltage = line[curly_start + 1..curly_end]
-        .split(',')
-        .map(|s| s.trim().parse().unwrap())
-        .collect();
+    if joltage.len() > MAX_LIGHTS {
+        return Err(ParseError::at(
+            input,
+            joltage_str,
+            "at most 16 joltage requirements",
+        ));
+    }
 
-    let button_section = &line[bracket_end + 1..curly_start];
-    let button_wiring = button_section
-        .split(')')
-        .filter(|s| s.contains('('))
-        .map(|s| {
-            let content = s.trim().trim_start_matches('(');
-            Indicator::parse_bit_set(content)
-        })
-        .collect();
+    let button_wiring = blocks(input, buttons, '(', ')')
+        .map(|wiring| Indicator::parse_bit_set(input, wiring?))
+        .collect::<Result<_, _>>()?;
 
-    Input {
+    Ok(Input {
         indicator,
         button_wiring,
         joltage,
-    }
+    })
 }
 
 #[derive(Debug)]
@@ -85,40 +104,45 @@ pub struct Input {
     pub(crate) joltage: Vec<u32>,
 }
 
-pub fn parse(input: &str) -> Vec<Input> {
-    input.lines().map(line_parser).collect()
+pub fn parse(input: &str) -> Result<Vec<Input>, ParseError> {
+    input.lines().map(|line| line_parser(input, line)).collect()
 }
 
 pub fn part_1(input: &[Input]) -> u32 {
     input
         .iter()
-        .map(|input| min_buttom_press(input.indicator, input.button_wiring.as_slice()) as u32)
+        .map(|input| {
+            min_buttom_press(input.indicator, input.button_wiring.as_slice())
+                .expect("light diagram out of reach of the buttons") as u32
+        })
         .sum()
 }
 
-fn min_buttom_press(expected: Indicator, wirings: &[Indicator]) -> usize {
-    let mut queue = VecDeque::new();
-    let mut visited = bitvec![u8, Lsb0; 0; 65536];
+/// Fewest presses turning every light off into `expected`, `None` if no presses do.
+fn min_buttom_press(expected: Indicator, wirings: &[Indicator]) -> Option<usize> {
+    let start = Indicator { bytes: 0 };
+    if expected == start {
+        return Some(0);
+    }
 
-    queue.push_back((Indicator { bytes: 0 }, 0));
+    // One bit for every possible indicator
+    let mut queue = VecDeque::new();
+    let mut visited = bitvec![u8, Lsb0; 0; 1 << MAX_LIGHTS];
+    queue.push_back((start, 0));
     visited.set(0, true);
 
     while let Some((curr, depth)) = queue.pop_front() {
         for &wiring in wirings {
             let next = curr ^ wiring;
-
             if next == expected {
-                return depth + 1;
+                return Some(depth + 1);
             }
-
-            if !visited[next.bytes as usize] {
-                visited.set(next.bytes as usize, true);
+            if !visited.replace(next.bytes as usize, true) {
                 queue.push_back((next, depth + 1));
             }
         }
     }
-
-    0
+    None
 }
 
 pub fn part_2(input: &[Input]) -> u32 {
@@ -177,3 +201,39 @@ fn min_buttom_press_constrained_by_joltage(wirings: &[Indicator], joltage: &[u32
         _ => unreachable!(),
     }
 }
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Input>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> impl Display {
+        part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> impl Display {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_min_button_press() {
+        let wirings = [Indicator { bytes: 0b011 }, Indicator { bytes: 0b110 }];
+        assert_eq!(
+            Some(2),
+            min_buttom_press(Indicator { bytes: 0b101 }, &wirings)
+        );
+        // Nothing to press when every light is already off
+        assert_eq!(Some(0), min_buttom_press(Indicator { bytes: 0 }, &wirings));
+        // No button reaches the light in the middle alone
+        assert_eq!(None, min_buttom_press(Indicator { bytes: 0b010 }, &wirings));
+    }
+}
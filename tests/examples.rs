@@ -0,0 +1,120 @@
+//! Runs every sample in `tests/examples/yearYYYY/dayNN` through the registered solution.
+//!
+//! A case is a directory holding `input.txt` and the expected `part1.txt` and/or `part2.txt`.
+//! Days with several samples keep each one in a subdirectory, e.g. `day01/spelled/`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_rs::registry;
+
+struct Case {
+    year: u32,
+    day: u32,
+    dir: PathBuf,
+}
+
+fn number(name: &str, prefix: &str) -> Option<u32> {
+    name.strip_prefix(prefix)?.parse().ok()
+}
+
+fn subdirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("could not read {}: {err}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn name(path: &Path) -> &str {
+    path.file_name().unwrap().to_str().unwrap()
+}
+
+fn discover(root: &Path) -> Vec<Case> {
+    let mut cases = Vec::new();
+    for year_dir in subdirs(root) {
+        let year = number(name(&year_dir), "year")
+            .unwrap_or_else(|| panic!("{} is not a yearYYYY directory", year_dir.display()));
+
+        for day_dir in subdirs(&year_dir) {
+            let day = number(name(&day_dir), "day")
+                .unwrap_or_else(|| panic!("{} is not a dayNN directory", day_dir.display()));
+
+            let dirs = if day_dir.join("input.txt").exists() {
+                vec![day_dir.clone()]
+            } else {
+                Vec::new()
+            };
+            for dir in dirs.into_iter().chain(subdirs(&day_dir)) {
+                cases.push(Case { year, day, dir });
+            }
+        }
+    }
+    cases
+}
+
+/// Checks one case, returning a description of every mismatch.
+fn run(case: &Case) -> Vec<String> {
+    let Some(puzzle) = registry::get(case.year, case.day) else {
+        return vec![format!("{} day {} is not registered", case.year, case.day)];
+    };
+
+    let read = |file: &str| fs::read_to_string(case.dir.join(file)).ok();
+    let Some(input) = read("input.txt") else {
+        return vec!["missing input.txt".to_string()];
+    };
+    let expected = [read("part1.txt"), read("part2.txt")];
+    if expected.iter().all(Option::is_none) {
+        return vec!["no part1.txt or part2.txt".to_string()];
+    }
+
+    let parsed = match puzzle.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => return vec![err.to_string()],
+    };
+
+    let mut failures = Vec::new();
+    for (part, (expected, implemented)) in (1..=2).zip(expected.iter().zip(puzzle.implemented)) {
+        let Some(expected) = expected else {
+            continue;
+        };
+        // Expected answers may be written before the part is solved
+        if !implemented {
+            continue;
+        }
+        let answer = if part == 1 {
+            parsed.part_1()
+        } else {
+            parsed.part_2()
+        };
+        if answer != expected.trim() {
+            failures.push(format!(
+                "part {part}: expected {}, got {answer}",
+                expected.trim()
+            ));
+        }
+    }
+    failures
+}
+
+#[test]
+fn test_examples() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples");
+    let cases = discover(&root);
+    assert!(!cases.is_empty(), "no examples in {}", root.display());
+
+    let failures: Vec<String> = cases
+        .iter()
+        .flat_map(|case| {
+            run(case)
+                .into_iter()
+                .map(move |failure| format!("{}: {failure}", case.dir.display()))
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,59 @@
+//! Replays the inputs that once crashed a fuzz target, see `fuzz/`.
+//!
+//! Every file in `tests/fuzz_regressions/yearYYYY/dayNN` is handed to the day's parser, which
+//! may reject it but must not panic.
+
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use aoc_rs::registry;
+
+fn entries(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("could not read {}: {err}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn number(path: &Path, prefix: &str) -> u32 {
+    path.file_name()
+        .and_then(|name| name.to_str()?.strip_prefix(prefix)?.parse().ok())
+        .unwrap_or_else(|| panic!("{} is not a {prefix}NN directory", path.display()))
+}
+
+#[test]
+fn test_fuzz_regressions() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fuzz_regressions");
+
+    let mut failures = Vec::new();
+    for year_dir in entries(&root) {
+        let year = number(&year_dir, "year");
+        for day_dir in entries(&year_dir) {
+            let day = number(&day_dir, "day");
+            let puzzle = registry::get(year, day)
+                .unwrap_or_else(|| panic!("{year} day {day} is not registered"));
+
+            for case in entries(&day_dir) {
+                let data = fs::read(&case).unwrap();
+                // The fuzz targets skip anything that is not UTF-8 as well
+                let Ok(input) = std::str::from_utf8(&data) else {
+                    continue;
+                };
+                if panic::catch_unwind(AssertUnwindSafe(|| puzzle.parse(input))).is_err() {
+                    failures.push(case.display().to_string());
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "parsers panicked on:\n{}",
+        failures.join("\n")
+    );
+}